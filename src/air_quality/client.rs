@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::{
+    air_quality::model::AirQuality,
+    cache::Cache,
+    client::{ClientBuilder, ClientError},
+    consts::TOKEN,
+    datetime::DateTime,
+    location::model::Coordinates,
+};
+
+pub const URL_AIR_POLLUTION: &str = "http://api.openweathermap.org/data/2.5/air_pollution";
+
+#[derive(Debug, Error)]
+pub enum AirQualityClientError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error("No air quality data available")]
+    NoData,
+}
+
+impl AirQualityClientError {
+    pub fn is_offline(&self) -> bool {
+        matches!(self, Self::Client(error) if error.is_offline())
+    }
+}
+
+#[derive(Debug)]
+pub struct AirQualityClient {
+    inner: ClientBuilder,
+    api_key: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct AirQualityMain {
+    aqi: i32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct AirQualityComponents {
+    pm2_5: f32,
+    pm10: f32,
+    o3: f32,
+    no2: f32,
+    so2: f32,
+    co: f32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct AirQualityEntryResponse {
+    dt: u64,
+    main: AirQualityMain,
+    #[serde(default)]
+    components: AirQualityComponents,
+}
+
+impl From<AirQualityEntryResponse> for AirQuality {
+    fn from(response: AirQualityEntryResponse) -> Self {
+        let components = response.components;
+        Self {
+            aqi: response.main.aqi,
+            pm2_5: components.pm2_5,
+            pm10: components.pm10,
+            o3: components.o3,
+            no2: components.no2,
+            so2: components.so2,
+            co: components.co,
+            date_time: DateTime::from_unix(response.dt),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct AirQualityResponse {
+    list: Vec<AirQualityEntryResponse>,
+}
+
+impl Default for AirQualityClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AirQualityClient {
+    pub fn new() -> Self {
+        Self {
+            inner: ClientBuilder::new(),
+            api_key: TOKEN.to_string(),
+        }
+    }
+
+    pub fn set_connect_timeout(self, timeout: Duration) -> Self {
+        self.with_inner(|inner| inner.set_connect_timeout(timeout))
+    }
+
+    pub fn set_timeout(self, timeout: Duration) -> Self {
+        self.with_inner(|inner| inner.set_timeout(timeout))
+    }
+
+    pub fn set_proxy(self, proxy: Option<&str>) -> Self {
+        self.with_inner(|inner| inner.set_proxy(proxy))
+    }
+
+    pub fn set_cache(self, cache: Option<Cache>) -> Self {
+        self.with_inner(|inner| inner.set_cache(cache))
+    }
+
+    pub fn set_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = api_key.to_string();
+        self
+    }
+
+    pub async fn get(self, coordinates: &Coordinates) -> Result<AirQuality, AirQualityClientError> {
+        let url = format!(
+            "{}?lat={lat}&lon={lon}&appid={appid}",
+            URL_AIR_POLLUTION,
+            lat = coordinates.latitude,
+            lon = coordinates.longitude,
+            appid = self.api_key
+        );
+        let res: AirQualityResponse = self
+            .inner
+            .build()?
+            .get(&url)
+            .await
+            .map_err(ClientError::for_api_key)?;
+        res.list
+            .into_iter()
+            .next()
+            .map(Into::into)
+            .ok_or(AirQualityClientError::NoData)
+    }
+
+    #[inline]
+    fn with_inner<F>(mut self, func: F) -> Self
+    where
+        F: FnOnce(ClientBuilder) -> ClientBuilder,
+    {
+        self.inner = func(self.inner);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::sleep;
+
+    use super::{AirQuality, AirQualityClient, AirQualityResponse};
+    use crate::location::model::Coordinates;
+
+    #[tokio::test]
+    async fn air_quality_client_get() {
+        sleep(Duration::from_secs(1)).await;
+        let coordinates = Coordinates::new(-7.9194, -37.175);
+        assert!(AirQualityClient::new().get(&coordinates).await.is_ok());
+    }
+
+    #[test]
+    fn air_quality_from_response() {
+        let json = "{
+            \"coord\": { \"lon\": -37.175, \"lat\": -7.9194 },
+            \"list\": [
+              {
+                \"main\": { \"aqi\": 2 },
+                \"components\": {
+                  \"co\": 201.94,
+                  \"no\": 0.01,
+                  \"no2\": 0.77,
+                  \"o3\": 68.66,
+                  \"so2\": 0.64,
+                  \"pm2_5\": 0.5,
+                  \"pm10\": 0.54,
+                  \"nh3\": 0.12
+                },
+                \"dt\": 1631620646
+              }
+            ]
+        }";
+        let response = serde_json::from_str::<AirQualityResponse>(json).unwrap();
+        let air_quality: AirQuality = response.list.into_iter().next().unwrap().into();
+        assert_eq!(air_quality.aqi, 2);
+        assert_eq!(air_quality.category(), "Fair");
+        assert_eq!(air_quality.pm2_5, 0.5);
+        assert_eq!(air_quality.pm10, 0.54);
+        assert_eq!(air_quality.o3, 68.66);
+        assert_eq!(air_quality.no2, 0.77);
+        assert_eq!(air_quality.so2, 0.64);
+        assert_eq!(air_quality.co, 201.94);
+        assert_eq!(air_quality.date_time.to_string(), "2021-09-14T11:57:26Z");
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::datetime::DateTime;
+
+#[derive(Clone, Debug)]
+pub struct AirQuality {
+    pub aqi: i32,
+    pub pm2_5: f32,
+    pub pm10: f32,
+    pub o3: f32,
+    pub no2: f32,
+    pub so2: f32,
+    pub co: f32,
+    pub date_time: DateTime,
+}
+
+impl AirQuality {
+    pub fn category(&self) -> &'static str {
+        match self.aqi {
+            1 => "Good",
+            2 => "Fair",
+            3 => "Moderate",
+            4 => "Poor",
+            5 => "Very poor",
+            _ => "Unknown",
+        }
+    }
+}
+
+impl Display for AirQuality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "Air quality: {aqi} ({category})
+  PM2.5: {pm2_5} μg/m³
+  PM10: {pm10} μg/m³
+  O3: {o3} μg/m³
+  NO2: {no2} μg/m³
+  SO2: {so2} μg/m³
+  CO: {co} μg/m³",
+            aqi = self.aqi,
+            category = self.category(),
+            pm2_5 = self.pm2_5,
+            pm10 = self.pm10,
+            o3 = self.o3,
+            no2 = self.no2,
+            so2 = self.so2,
+            co = self.co
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AirQuality;
+    use crate::datetime::DateTime;
+
+    #[test]
+    fn air_quality_format() {
+        let mut air_quality = AirQuality {
+            aqi: 2,
+            pm2_5: 0.5,
+            pm10: 0.54,
+            o3: 68.66,
+            no2: 0.77,
+            so2: 0.64,
+            co: 201.94,
+            date_time: DateTime::from_unix(1631620646),
+        };
+        let text = "Air quality: 2 (Fair)
+  PM2.5: 0.5 μg/m³
+  PM10: 0.54 μg/m³
+  O3: 68.66 μg/m³
+  NO2: 0.77 μg/m³
+  SO2: 0.64 μg/m³
+  CO: 201.94 μg/m³";
+        assert_eq!(air_quality.to_string(), text);
+        assert_eq!(air_quality.category(), "Fair");
+        air_quality.aqi = 5;
+        assert_eq!(air_quality.category(), "Very poor");
+        air_quality.aqi = 0;
+        assert_eq!(air_quality.category(), "Unknown");
+    }
+}
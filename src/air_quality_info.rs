@@ -0,0 +1,63 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{air_quality::model::AirQuality, location::model::Location};
+
+#[derive(Clone, Debug)]
+pub struct AirQualityInfo<'a> {
+    location: &'a Location,
+    air_quality: &'a AirQuality,
+}
+
+impl<'a> AirQualityInfo<'a> {
+    pub fn new(location: &'a Location, air_quality: &'a AirQuality) -> Self {
+        Self {
+            location,
+            air_quality,
+        }
+    }
+}
+
+impl Display for AirQualityInfo<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{city}, {country}:\n{air_quality}",
+            city = self.location.city,
+            country = self.location.country,
+            air_quality = self.air_quality
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AirQualityInfo;
+    use crate::{air_quality::model::AirQuality, datetime::DateTime, fixtures};
+
+    #[test]
+    fn air_quality_info() {
+        let location = fixtures::location();
+        let air_quality = AirQuality {
+            aqi: 4,
+            pm2_5: 60.2,
+            pm10: 88.1,
+            o3: 140.0,
+            no2: 41.5,
+            so2: 12.0,
+            co: 700.9,
+            date_time: DateTime::from_unix(1631620646),
+        };
+        let text = "Monteiro, Brazil:
+Air quality: 4 (Poor)
+  PM2.5: 60.2 μg/m³
+  PM10: 88.1 μg/m³
+  O3: 140 μg/m³
+  NO2: 41.5 μg/m³
+  SO2: 12 μg/m³
+  CO: 700.9 μg/m³";
+        assert_eq!(
+            AirQualityInfo::new(&location, &air_quality).to_string(),
+            text
+        );
+    }
+}
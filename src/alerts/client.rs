@@ -0,0 +1,305 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::{
+    alerts::model::{Alert, AlertSeverity, Alerts},
+    cache::Cache,
+    client::{ClientBuilder, ClientError},
+    consts::TOKEN,
+    datetime::DateTime,
+    location::model::Coordinates,
+    weather::provider::WeatherProviderKind,
+};
+
+pub const URL_ONE_CALL: &str = "https://api.openweathermap.org/data/3.0/onecall";
+pub const URL_NWS_ALERTS: &str = "https://api.weather.gov/alerts/active";
+
+#[derive(Debug, Error)]
+pub enum AlertsClientError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+}
+
+impl AlertsClientError {
+    pub fn is_offline(&self) -> bool {
+        matches!(self, Self::Client(error) if error.is_offline())
+    }
+}
+
+#[derive(Debug)]
+pub struct AlertsClient {
+    inner: ClientBuilder,
+    api_key: String,
+    provider: WeatherProviderKind,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct AlertResponse {
+    #[serde(default)]
+    sender_name: String,
+    event: String,
+    start: u64,
+    end: u64,
+}
+
+impl From<AlertResponse> for Alert {
+    fn from(response: AlertResponse) -> Self {
+        Self {
+            severity: AlertSeverity::from_event(&response.event),
+            event: response.event,
+            start: DateTime::from_unix(response.start),
+            end: DateTime::from_unix(response.end),
+            sender: response.sender_name,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct OneCallResponse {
+    #[serde(default)]
+    alerts: Vec<AlertResponse>,
+    #[serde(default)]
+    timezone_offset: i32,
+}
+
+impl From<OneCallResponse> for Alerts {
+    fn from(response: OneCallResponse) -> Self {
+        let offset = response.timezone_offset;
+        Self {
+            entries: response
+                .alerts
+                .into_iter()
+                .map(Alert::from)
+                .map(|mut alert| {
+                    alert.start = alert.start.set_offset(offset);
+                    alert.end = alert.end.set_offset(offset);
+                    alert
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NwsAlertProperties {
+    event: String,
+    #[serde(default)]
+    severity: String,
+    #[serde(default)]
+    sender_name: String,
+    onset: Option<DateTime>,
+    effective: DateTime,
+    ends: Option<DateTime>,
+    expires: DateTime,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct NwsAlertFeature {
+    properties: NwsAlertProperties,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct NwsAlertsResponse {
+    #[serde(default)]
+    features: Vec<NwsAlertFeature>,
+}
+
+impl From<NwsAlertProperties> for Alert {
+    fn from(properties: NwsAlertProperties) -> Self {
+        let severity = match AlertSeverity::from_level(&properties.severity) {
+            AlertSeverity::Unknown => AlertSeverity::from_event(&properties.event),
+            severity => severity,
+        };
+        Self {
+            event: properties.event,
+            severity,
+            start: properties.onset.unwrap_or(properties.effective),
+            end: properties.ends.unwrap_or(properties.expires),
+            sender: properties.sender_name,
+        }
+    }
+}
+
+impl From<NwsAlertsResponse> for Alerts {
+    fn from(response: NwsAlertsResponse) -> Self {
+        Self {
+            entries: response
+                .features
+                .into_iter()
+                .map(|feature| feature.properties.into())
+                .collect(),
+        }
+    }
+}
+
+impl Default for AlertsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AlertsClient {
+    pub fn new() -> Self {
+        Self {
+            inner: ClientBuilder::new(),
+            api_key: TOKEN.to_string(),
+            provider: WeatherProviderKind::default(),
+        }
+    }
+
+    pub fn set_connect_timeout(self, timeout: Duration) -> Self {
+        self.with_inner(|inner| inner.set_connect_timeout(timeout))
+    }
+
+    pub fn set_timeout(self, timeout: Duration) -> Self {
+        self.with_inner(|inner| inner.set_timeout(timeout))
+    }
+
+    pub fn set_proxy(self, proxy: Option<&str>) -> Self {
+        self.with_inner(|inner| inner.set_proxy(proxy))
+    }
+
+    pub fn set_cache(self, cache: Option<Cache>) -> Self {
+        self.with_inner(|inner| inner.set_cache(cache))
+    }
+
+    pub fn set_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = api_key.to_string();
+        self
+    }
+
+    pub fn set_provider(mut self, provider: WeatherProviderKind) -> Self {
+        self.provider = provider;
+        self
+    }
+
+    pub async fn get(self, coordinates: &Coordinates) -> Result<Alerts, AlertsClientError> {
+        if self.provider == WeatherProviderKind::Nws {
+            let url = format!(
+                "{}?point={lat:.4},{lon:.4}",
+                URL_NWS_ALERTS,
+                lat = coordinates.latitude,
+                lon = coordinates.longitude
+            );
+            let res: NwsAlertsResponse = self.inner.build()?.get(&url).await?;
+            return Ok(res.into());
+        }
+        let url = format!(
+            "{}?lat={lat}&lon={lon}&exclude=current,minutely,hourly,daily&appid={appid}",
+            URL_ONE_CALL,
+            lat = coordinates.latitude,
+            lon = coordinates.longitude,
+            appid = self.api_key
+        );
+        let res: OneCallResponse = self
+            .inner
+            .build()?
+            .get(&url)
+            .await
+            .map_err(ClientError::for_api_key)?;
+        Ok(res.into())
+    }
+
+    #[inline]
+    fn with_inner<F>(mut self, func: F) -> Self
+    where
+        F: FnOnce(ClientBuilder) -> ClientBuilder,
+    {
+        self.inner = func(self.inner);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alerts, NwsAlertsResponse, OneCallResponse};
+    use crate::alerts::model::AlertSeverity;
+
+    #[test]
+    fn alerts_from_response() {
+        let json = "{
+            \"lat\": 40.7834,
+            \"lon\": -73.9662,
+            \"timezone\": \"America/New_York\",
+            \"timezone_offset\": -14400,
+            \"alerts\": [
+              {
+                \"sender_name\": \"NWS New York City\",
+                \"event\": \"Heat Advisory\",
+                \"start\": 1631620000,
+                \"end\": 1631660000,
+                \"description\": \"...HEAT ADVISORY IN EFFECT...\",
+                \"tags\": [\"Extreme temperature value\"]
+              }
+            ]
+        }";
+        let alerts: Alerts = serde_json::from_str::<OneCallResponse>(json)
+            .unwrap()
+            .into();
+        assert_eq!(alerts.entries.len(), 1);
+        let alert = &alerts.entries[0];
+        assert_eq!(alert.event, "Heat Advisory");
+        assert_eq!(alert.severity, AlertSeverity::Minor);
+        assert_eq!(alert.sender, "NWS New York City");
+        assert_eq!(alert.start.to_string(), "2021-09-14T07:46:40-04:00");
+        assert_eq!(alert.end.to_string(), "2021-09-14T18:53:20-04:00");
+
+        let alerts: Alerts = serde_json::from_str::<OneCallResponse>("{\"lat\": 40.7834}")
+            .unwrap()
+            .into();
+        assert!(alerts.is_empty());
+    }
+
+    #[test]
+    fn alerts_from_nws_response() {
+        let json = "{
+            \"type\": \"FeatureCollection\",
+            \"features\": [
+              {
+                \"properties\": {
+                  \"event\": \"Flash Flood Warning\",
+                  \"severity\": \"Severe\",
+                  \"senderName\": \"NWS New York NY\",
+                  \"sent\": \"2021-09-01T20:15:00-04:00\",
+                  \"effective\": \"2021-09-01T20:15:00-04:00\",
+                  \"onset\": \"2021-09-01T20:30:00-04:00\",
+                  \"expires\": \"2021-09-01T23:00:00-04:00\",
+                  \"ends\": null
+                }
+              },
+              {
+                \"properties\": {
+                  \"event\": \"Heat Advisory\",
+                  \"severity\": \"Unknown\",
+                  \"senderName\": \"NWS New York NY\",
+                  \"effective\": \"2021-09-01T10:00:00-04:00\",
+                  \"expires\": \"2021-09-01T20:00:00-04:00\",
+                  \"ends\": \"2021-09-01T21:00:00-04:00\"
+                }
+              }
+            ]
+        }";
+        let alerts: Alerts = serde_json::from_str::<NwsAlertsResponse>(json)
+            .unwrap()
+            .into();
+        assert_eq!(alerts.entries.len(), 2);
+        let alert = &alerts.entries[0];
+        assert_eq!(alert.event, "Flash Flood Warning");
+        assert_eq!(alert.severity, AlertSeverity::Severe);
+        assert_eq!(alert.sender, "NWS New York NY");
+        assert_eq!(alert.start.to_string(), "2021-09-01T20:30:00-04:00");
+        assert_eq!(alert.end.to_string(), "2021-09-01T23:00:00-04:00");
+        let alert = &alerts.entries[1];
+        assert_eq!(alert.severity, AlertSeverity::Minor);
+        assert_eq!(alert.start.to_string(), "2021-09-01T10:00:00-04:00");
+        assert_eq!(alert.end.to_string(), "2021-09-01T21:00:00-04:00");
+
+        let alerts: Alerts = serde_json::from_str::<NwsAlertsResponse>("{\"features\": []}")
+            .unwrap()
+            .into();
+        assert!(alerts.is_empty());
+    }
+}
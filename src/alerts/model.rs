@@ -0,0 +1,187 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::datetime::{DateTime, DateTimeFormat};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AlertSeverity {
+    Unknown,
+    Minor,
+    Moderate,
+    Severe,
+    Extreme,
+}
+
+impl AlertSeverity {
+    pub fn from_event(event: &str) -> Self {
+        let event = event.to_lowercase();
+        if event.contains("extreme") || event.contains("emergency") {
+            Self::Extreme
+        } else if event.contains("warning") {
+            Self::Severe
+        } else if event.contains("watch") {
+            Self::Moderate
+        } else if event.contains("advisory") || event.contains("statement") {
+            Self::Minor
+        } else {
+            Self::Unknown
+        }
+    }
+
+    pub fn from_level(level: &str) -> Self {
+        match level.to_lowercase().as_str() {
+            "extreme" => Self::Extreme,
+            "severe" => Self::Severe,
+            "moderate" => Self::Moderate,
+            "minor" => Self::Minor,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for AlertSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let severity = match *self {
+            Self::Unknown => "unknown",
+            Self::Minor => "minor",
+            Self::Moderate => "moderate",
+            Self::Severe => "severe",
+            Self::Extreme => "extreme",
+        };
+        write!(f, "{}", severity)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Alert {
+    pub event: String,
+    pub severity: AlertSeverity,
+    pub start: DateTime,
+    pub end: DateTime,
+    pub sender: String,
+}
+
+impl Alert {
+    pub fn is_active(&self, now: &DateTime) -> bool {
+        self.start.unix() <= now.unix() && now.unix() < self.end.unix()
+    }
+
+    pub fn format(&self, format: &DateTimeFormat) -> String {
+        format!(
+            "⚠️  {event} [{severity}]
+   From: {start}
+   Until: {end}
+   Sender: {sender}",
+            event = self.event.to_uppercase(),
+            severity = self.severity,
+            start = self.start.format(format),
+            end = self.end.format(format),
+            sender = self.sender
+        )
+    }
+}
+
+impl Display for Alert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.format(&DateTimeFormat::default()))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Alerts {
+    pub entries: Vec<Alert>,
+}
+
+impl Alerts {
+    pub fn active(self, now: &DateTime) -> Self {
+        Self {
+            entries: self
+                .entries
+                .into_iter()
+                .filter(|alert| alert.is_active(now))
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alert, AlertSeverity, Alerts};
+    use crate::datetime::DateTime;
+
+    fn alert(event: &str, start: u64, end: u64) -> Alert {
+        Alert {
+            event: event.to_string(),
+            severity: AlertSeverity::from_event(event),
+            start: DateTime::from_unix(start),
+            end: DateTime::from_unix(end),
+            sender: "NWS New York City".to_string(),
+        }
+    }
+
+    #[test]
+    fn alert_severity_from_event() {
+        assert_eq!(
+            AlertSeverity::from_event("Extreme Heat Warning"),
+            AlertSeverity::Extreme
+        );
+        assert_eq!(
+            AlertSeverity::from_event("Flood Warning"),
+            AlertSeverity::Severe
+        );
+        assert_eq!(
+            AlertSeverity::from_event("Tornado Watch"),
+            AlertSeverity::Moderate
+        );
+        assert_eq!(
+            AlertSeverity::from_event("Heat Advisory"),
+            AlertSeverity::Minor
+        );
+        assert_eq!(
+            AlertSeverity::from_event("Hitzewelle"),
+            AlertSeverity::Unknown
+        );
+    }
+
+    #[test]
+    fn alert_severity_from_level() {
+        assert_eq!(AlertSeverity::from_level("Extreme"), AlertSeverity::Extreme);
+        assert_eq!(AlertSeverity::from_level("severe"), AlertSeverity::Severe);
+        assert_eq!(
+            AlertSeverity::from_level("Moderate"),
+            AlertSeverity::Moderate
+        );
+        assert_eq!(AlertSeverity::from_level("Minor"), AlertSeverity::Minor);
+        assert_eq!(AlertSeverity::from_level("Unknown"), AlertSeverity::Unknown);
+    }
+
+    #[test]
+    fn alerts_active() {
+        let alerts = Alerts {
+            entries: vec![
+                alert("Heat Advisory", 1631620000, 1631660000),
+                alert("Flood Watch", 1631500000, 1631600000),
+                alert("Tornado Watch", 1631630000, 1631640000),
+            ],
+        };
+        let active = alerts.active(&DateTime::from_unix(1631620646));
+        assert_eq!(active.entries.len(), 1);
+        assert_eq!(active.entries[0].event, "Heat Advisory");
+        assert!(Alerts::default().active(&DateTime::from_unix(0)).is_empty());
+    }
+
+    #[test]
+    fn alert_display() {
+        let text = "⚠️  HEAT ADVISORY [minor]
+   From: 2021-09-14T11:46:40Z
+   Until: 2021-09-14T22:53:20Z
+   Sender: NWS New York City";
+        assert_eq!(
+            alert("Heat Advisory", 1631620000, 1631660000).to_string(),
+            text
+        );
+    }
+}
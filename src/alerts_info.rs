@@ -0,0 +1,85 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{alerts::model::Alerts, datetime::DateTimeFormat, location::model::Location};
+
+#[derive(Clone, Debug)]
+pub struct AlertsInfo<'a> {
+    location: &'a Location,
+    alerts: &'a Alerts,
+    date_time_format: DateTimeFormat,
+}
+
+impl<'a> AlertsInfo<'a> {
+    pub fn new(location: &'a Location, alerts: &'a Alerts) -> Self {
+        Self {
+            location,
+            alerts,
+            date_time_format: DateTimeFormat::default(),
+        }
+    }
+
+    pub fn set_date_time_format(mut self, date_time_format: DateTimeFormat) -> Self {
+        self.date_time_format = date_time_format;
+        self
+    }
+}
+
+impl Display for AlertsInfo<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{city}, {country}:",
+            city = self.location.city,
+            country = self.location.country
+        )?;
+        if self.alerts.is_empty() {
+            return write!(f, " no active alerts");
+        }
+        for alert in &self.alerts.entries {
+            write!(f, "\n{}", alert.format(&self.date_time_format))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AlertsInfo;
+    use crate::{
+        alerts::model::{Alert, AlertSeverity, Alerts},
+        datetime::DateTime,
+        fixtures,
+        location::model::{Coordinates, Location},
+    };
+
+    #[test]
+    fn alerts_info() {
+        let location = Location {
+            city: "New York".to_string(),
+            country: "United States".to_string(),
+            coordinates: Coordinates::new(40.7834, -73.9662),
+            ..fixtures::location()
+        };
+        let alerts = Alerts::default();
+        assert_eq!(
+            AlertsInfo::new(&location, &alerts).to_string(),
+            "New York, United States: no active alerts"
+        );
+
+        let alerts = Alerts {
+            entries: vec![Alert {
+                event: "Flood Warning".to_string(),
+                severity: AlertSeverity::Severe,
+                start: DateTime::from_unix(1631620000),
+                end: DateTime::from_unix(1631660000),
+                sender: "NWS New York City".to_string(),
+            }],
+        };
+        let text = "New York, United States:
+⚠️  FLOOD WARNING [severe]
+   From: 2021-09-14T11:46:40Z
+   Until: 2021-09-14T22:53:20Z
+   Sender: NWS New York City";
+        assert_eq!(AlertsInfo::new(&location, &alerts).to_string(), text);
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    env,
+    fmt::{Display, Formatter, Result},
+    fs,
+    path::PathBuf,
+};
+
+use crate::{
+    consts::{API_KEY_ENV, API_KEY_FILE, TOKEN},
+    paths,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ApiKeySource {
+    Argument,
+    Environment,
+    File(PathBuf),
+    BuiltIn,
+}
+
+impl Display for ApiKeySource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Argument => write!(f, "command line"),
+            Self::Environment => write!(f, "{} environment variable", API_KEY_ENV),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::BuiltIn => write!(f, "built-in"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApiKey {
+    pub value: String,
+    pub source: ApiKeySource,
+}
+
+impl Default for ApiKey {
+    fn default() -> Self {
+        Self {
+            value: TOKEN.to_string(),
+            source: ApiKeySource::BuiltIn,
+        }
+    }
+}
+
+impl ApiKey {
+    pub fn resolve(argument: Option<String>) -> Self {
+        Self::resolve_from(
+            argument,
+            env::var(API_KEY_ENV).ok(),
+            paths::config_file(API_KEY_FILE),
+        )
+    }
+
+    pub fn resolve_from(
+        argument: Option<String>,
+        environment: Option<String>,
+        file: Option<PathBuf>,
+    ) -> Self {
+        let non_empty = |value: String| {
+            let value = value.trim().to_string();
+            if value.is_empty() {
+                None
+            } else {
+                Some(value)
+            }
+        };
+        if let Some(value) = argument.and_then(non_empty) {
+            return Self {
+                value,
+                source: ApiKeySource::Argument,
+            };
+        }
+        if let Some(value) = environment.and_then(non_empty) {
+            return Self {
+                value,
+                source: ApiKeySource::Environment,
+            };
+        }
+        if let Some(path) = file {
+            if let Some(value) = fs::read_to_string(&path).ok().and_then(non_empty) {
+                return Self {
+                    value,
+                    source: ApiKeySource::File(path),
+                };
+            }
+        }
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{ApiKey, ApiKeySource};
+    use crate::consts::TOKEN;
+
+    #[test]
+    fn api_key_resolve() {
+        let path = env::temp_dir().join(format!("wethr-api-key-{}", process::id()));
+        fs::write(&path, "file-key\n").unwrap();
+
+        let key = ApiKey::resolve_from(
+            Some("arg-key".to_string()),
+            Some("env-key".to_string()),
+            Some(path.clone()),
+        );
+        assert_eq!(key.value, "arg-key");
+        assert_eq!(key.source, ApiKeySource::Argument);
+
+        let key = ApiKey::resolve_from(None, Some("env-key".to_string()), Some(path.clone()));
+        assert_eq!(key.value, "env-key");
+        assert_eq!(key.source, ApiKeySource::Environment);
+
+        let key = ApiKey::resolve_from(Some(" ".to_string()), None, Some(path.clone()));
+        assert_eq!(key.value, "file-key");
+        assert_eq!(key.source, ApiKeySource::File(path.clone()));
+
+        fs::remove_file(&path).unwrap();
+        let key = ApiKey::resolve_from(None, Some("".to_string()), Some(path));
+        assert_eq!(key.value, TOKEN);
+        assert_eq!(key.source, ApiKeySource::BuiltIn);
+    }
+
+    #[test]
+    fn api_key_source_display() {
+        assert_eq!(ApiKeySource::Argument.to_string(), "command line");
+        assert_eq!(
+            ApiKeySource::Environment.to_string(),
+            "WETHR_API_KEY environment variable"
+        );
+        assert_eq!(ApiKeySource::BuiltIn.to_string(), "built-in");
+    }
+}
@@ -0,0 +1,325 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{
+    datetime::{DateTime, DateTimeFormat},
+    location::model::Coordinates,
+    solar::{from_julian, to_julian, SolarDay, EARTH_OBLIQUITY, J2000},
+};
+
+const SYNODIC_MONTH: f64 = 29.530588853;
+
+const NEW_MOON_J2000: f64 = 2451550.1;
+
+const MOON_HORIZON: f64 = 0.133;
+
+const CIVIL_TWILIGHT: f64 = -6.0;
+
+const NAUTICAL_TWILIGHT: f64 = -12.0;
+
+const ASTRONOMICAL_TWILIGHT: f64 = -18.0;
+
+const GOLDEN_HOUR: f64 = 6.0;
+
+const BLUE_HOUR: f64 = -4.0;
+
+const HOURS_PER_DAY: f64 = 24.0;
+
+fn moon_coordinates(days: f64) -> (f64, f64) {
+    let longitude = (218.316 + 13.176396 * days).to_radians();
+    let anomaly = (134.963 + 13.064993 * days).to_radians();
+    let argument = (93.272 + 13.229350 * days).to_radians();
+    let ecliptic_longitude = longitude + 6.289_f64.to_radians() * anomaly.sin();
+    let ecliptic_latitude = 5.128_f64.to_radians() * argument.sin();
+    let obliquity = EARTH_OBLIQUITY.to_radians();
+    let right_ascension = (ecliptic_longitude.sin() * obliquity.cos()
+        - ecliptic_latitude.tan() * obliquity.sin())
+    .atan2(ecliptic_longitude.cos());
+    let declination = (ecliptic_latitude.sin() * obliquity.cos()
+        + ecliptic_latitude.cos() * obliquity.sin() * ecliptic_longitude.sin())
+    .asin();
+    (right_ascension, declination)
+}
+
+fn moon_altitude(coordinates: &Coordinates, julian: f64) -> f64 {
+    let days = julian - J2000;
+    let latitude = (coordinates.latitude as f64).to_radians();
+    let (right_ascension, declination) = moon_coordinates(days);
+    let sidereal_time =
+        (280.16 + 360.9856235 * days).to_radians() + (coordinates.longitude as f64).to_radians();
+    let hour_angle = sidereal_time - right_ascension;
+    let altitude = (latitude.sin() * declination.sin()
+        + latitude.cos() * declination.cos() * hour_angle.cos())
+    .asin();
+    let refraction = altitude.max(0.0);
+    altitude + 0.0002967 / (refraction + 0.00312536 / (refraction + 0.08901179)).tan()
+}
+
+fn moon_rise_set(
+    coordinates: &Coordinates,
+    date_time: &DateTime,
+) -> (Option<DateTime>, Option<DateTime>) {
+    let midnight = to_julian(&date_time.midnight());
+    let altitude = |hours: f64| {
+        moon_altitude(coordinates, midnight + hours / HOURS_PER_DAY) - MOON_HORIZON.to_radians()
+    };
+    let (mut rise, mut set) = (None, None);
+    let mut h0 = altitude(0.0);
+    let mut hour = 1.0;
+    while hour <= HOURS_PER_DAY {
+        let h1 = altitude(hour);
+        let h2 = altitude(hour + 1.0);
+        let a = (h0 + h2) / 2.0 - h1;
+        let b = (h2 - h0) / 2.0;
+        let xe = -b / (2.0 * a);
+        let ye = (a * xe + b) * xe + h1;
+        let d = b * b - 4.0 * a * h1;
+        let mut roots = 0;
+        let (mut x1, mut x2) = (0.0, 0.0);
+        if d >= 0.0 {
+            let dx = d.sqrt() / (a.abs() * 2.0);
+            x1 = xe - dx;
+            x2 = xe + dx;
+            if x1.abs() <= 1.0 {
+                roots += 1;
+            }
+            if x2.abs() <= 1.0 {
+                roots += 1;
+            }
+            if x1 < -1.0 {
+                x1 = x2;
+            }
+        }
+        if roots == 1 {
+            if h0 < 0.0 {
+                rise = Some(hour + x1);
+            } else {
+                set = Some(hour + x1);
+            }
+        } else if roots == 2 {
+            rise = Some(hour + if ye < 0.0 { x2 } else { x1 });
+            set = Some(hour + if ye < 0.0 { x1 } else { x2 });
+        }
+        if rise.is_some() && set.is_some() {
+            break;
+        }
+        h0 = h2;
+        hour += 2.0;
+    }
+    let to_date_time =
+        |hours: f64| from_julian(midnight + hours / HOURS_PER_DAY).set_offset(date_time.offset());
+    (rise.map(to_date_time), set.map(to_date_time))
+}
+
+#[derive(Clone, Debug)]
+pub struct Period {
+    pub start: DateTime,
+    pub end: DateTime,
+}
+
+impl Period {
+    fn new((start, end): (DateTime, DateTime)) -> Self {
+        Self { start, end }
+    }
+
+    pub fn format(&self, format: &DateTimeFormat) -> String {
+        format!(
+            "{} - {}",
+            self.start.format(format),
+            self.end.format(format)
+        )
+    }
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.format(&DateTimeFormat::default()))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Astronomy {
+    pub moon_phase: f64,
+    pub moon_illumination: f64,
+    pub moonrise: Option<DateTime>,
+    pub moonset: Option<DateTime>,
+    pub civil_twilight: Option<Period>,
+    pub nautical_twilight: Option<Period>,
+    pub astronomical_twilight: Option<Period>,
+    pub golden_hour: Option<(Period, Period)>,
+    pub blue_hour: Option<(Period, Period)>,
+}
+
+impl Astronomy {
+    pub fn new(coordinates: &Coordinates, date_time: &DateTime) -> Self {
+        let solar_day = SolarDay::new(coordinates, date_time);
+        let twilight = |altitude| solar_day.rise_set(altitude).map(Period::new);
+        let hours = |low, high| {
+            let (low_rise, low_set) = solar_day.rise_set(low)?;
+            let (high_rise, high_set) = solar_day.rise_set(high)?;
+            Some((
+                Period::new((low_rise, high_rise)),
+                Period::new((high_set, low_set)),
+            ))
+        };
+        let moon_phase = ((to_julian(date_time) - NEW_MOON_J2000) / SYNODIC_MONTH).rem_euclid(1.0);
+        let (moonrise, moonset) = moon_rise_set(coordinates, date_time);
+        Self {
+            moon_phase,
+            moon_illumination: (1.0 - (moon_phase * 2.0 * std::f64::consts::PI).cos()) / 2.0,
+            moonrise,
+            moonset,
+            civil_twilight: twilight(CIVIL_TWILIGHT),
+            nautical_twilight: twilight(NAUTICAL_TWILIGHT),
+            astronomical_twilight: twilight(ASTRONOMICAL_TWILIGHT),
+            golden_hour: hours(BLUE_HOUR, GOLDEN_HOUR),
+            blue_hour: hours(CIVIL_TWILIGHT, BLUE_HOUR),
+        }
+    }
+
+    pub fn moon_phase_name(&self) -> &'static str {
+        match (self.moon_phase * 8.0).round() as u8 {
+            1 => "Waxing crescent",
+            2 => "First quarter",
+            3 => "Waxing gibbous",
+            4 => "Full moon",
+            5 => "Waning gibbous",
+            6 => "Last quarter",
+            7 => "Waning crescent",
+            _ => "New moon",
+        }
+    }
+
+    pub fn format(&self, format: &DateTimeFormat) -> String {
+        let date_time = |date_time: &DateTime| date_time.format(format);
+        let period = |period: &Period| period.format(format);
+        let format_hours = |hours: &Option<(Period, Period)>| match hours {
+            Some((morning, evening)) => format!(
+                "\n  Morning: {}\n  Evening: {}",
+                period(morning),
+                period(evening)
+            ),
+            None => " N/D".to_string(),
+        };
+        format!(
+            "Moon: {phase}, {illumination}% illuminated
+Moonrise: {moonrise}
+Moonset: {moonset}
+Twilight:
+  Civil: {civil}
+  Nautical: {nautical}
+  Astronomical: {astronomical}
+Golden hour:{golden_hour}
+Blue hour:{blue_hour}",
+            phase = self.moon_phase_name(),
+            illumination = (self.moon_illumination * 100.0).round(),
+            moonrise = format_option(&self.moonrise, date_time),
+            moonset = format_option(&self.moonset, date_time),
+            civil = format_option(&self.civil_twilight, period),
+            nautical = format_option(&self.nautical_twilight, period),
+            astronomical = format_option(&self.astronomical_twilight, period),
+            golden_hour = format_hours(&self.golden_hour),
+            blue_hour = format_hours(&self.blue_hour)
+        )
+    }
+}
+
+fn format_option<T, F: Fn(&T) -> String>(value: &Option<T>, format: F) -> String {
+    value.as_ref().map_or_else(|| "N/D".to_string(), format)
+}
+
+impl Display for Astronomy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.format(&DateTimeFormat::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{moon_altitude, Astronomy, MOON_HORIZON};
+    use crate::{datetime::DateTime, location::model::Coordinates, solar::to_julian};
+
+    fn monteiro() -> Coordinates {
+        Coordinates::new(-7.9194, -37.175)
+    }
+
+    #[test]
+    fn astronomy_moon_phase() {
+        let astronomy = Astronomy::new(&monteiro(), &DateTime::from_unix(1630975860));
+        assert!(astronomy.moon_illumination < 0.01);
+        assert_eq!(astronomy.moon_phase_name(), "New moon");
+        let astronomy = Astronomy::new(&monteiro(), &DateTime::from_unix(1631565540));
+        assert!((astronomy.moon_illumination - 0.5).abs() < 0.05);
+        assert_eq!(astronomy.moon_phase_name(), "First quarter");
+        let astronomy = Astronomy::new(&monteiro(), &DateTime::from_unix(1632182040));
+        assert!(astronomy.moon_illumination > 0.99);
+        assert_eq!(astronomy.moon_phase_name(), "Full moon");
+        let astronomy = Astronomy::new(&monteiro(), &DateTime::from_unix(1632880620));
+        assert_eq!(astronomy.moon_phase_name(), "Last quarter");
+    }
+
+    #[test]
+    fn astronomy_moonrise_moonset() {
+        let coordinates = monteiro();
+        let astronomy = Astronomy::new(&coordinates, &DateTime::from_unix(1631620646));
+        let moonrise = astronomy.moonrise.unwrap();
+        let moonset = astronomy.moonset.unwrap();
+        assert_eq!(moonrise.date(), "2021-09-14");
+        assert_eq!(moonset.date(), "2021-09-14");
+        assert!(moonset.unix() < moonrise.unix());
+        for date_time in &[moonrise, moonset] {
+            let altitude = moon_altitude(&coordinates, to_julian(date_time));
+            assert!((altitude - MOON_HORIZON.to_radians()).abs() < 0.005);
+        }
+
+        let london = Coordinates::new(51.5074, -0.1278);
+        let astronomy = Astronomy::new(&london, &DateTime::from_unix(1631620646));
+        assert_eq!(astronomy.moonrise.unwrap().time(), "14:59");
+        assert_eq!(astronomy.moonset.unwrap().time(), "22:26");
+    }
+
+    #[test]
+    fn astronomy_local_day() {
+        // 23:00 on 2021-09-14 in Monteiro is already 2021-09-15 in UTC.
+        let date_time = DateTime::from_unix(1631671200).set_offset(-10800);
+        let astronomy = Astronomy::new(&monteiro(), &date_time);
+        let moonrise = astronomy.moonrise.unwrap();
+        assert_eq!(moonrise.date(), "2021-09-14");
+        assert_eq!(moonrise.time(), "11:46");
+        // The 02:48Z moonset falls at 23:48 on the previous local day.
+        assert!(astronomy.moonset.is_none());
+        let civil = astronomy.civil_twilight.unwrap();
+        assert_eq!(civil.start.date(), "2021-09-14");
+        assert_eq!(civil.start.time(), "05:01");
+    }
+
+    #[test]
+    fn astronomy_twilight_and_hours() {
+        let astronomy = Astronomy::new(&monteiro(), &DateTime::from_unix(1631620646));
+        let civil = astronomy.civil_twilight.unwrap();
+        let nautical = astronomy.nautical_twilight.unwrap();
+        let astronomical = astronomy.astronomical_twilight.unwrap();
+        assert!(astronomical.start.unix() < nautical.start.unix());
+        assert!(nautical.start.unix() < civil.start.unix());
+        assert!(civil.start.unix() < 1631607769);
+        assert!(civil.end.unix() > 1631651152);
+        assert!(civil.end.unix() < nautical.end.unix());
+        assert!(nautical.end.unix() < astronomical.end.unix());
+        assert_eq!(civil.start.time(), "08:01");
+        assert_eq!(civil.end.time(), "20:46");
+
+        let (morning_blue, evening_blue) = astronomy.blue_hour.unwrap();
+        let (morning_golden, evening_golden) = astronomy.golden_hour.unwrap();
+        assert_eq!(morning_blue.start.unix(), civil.start.unix());
+        assert_eq!(morning_blue.end.unix(), morning_golden.start.unix());
+        assert!(morning_golden.end.unix() > 1631607769);
+        assert!(evening_golden.start.unix() < 1631651152);
+        assert_eq!(evening_golden.end.unix(), evening_blue.start.unix());
+        assert_eq!(evening_blue.end.unix(), civil.end.unix());
+
+        let svalbard = Coordinates::new(78.2232, 15.6267);
+        let astronomy = Astronomy::new(&svalbard, &DateTime::from_unix(1624233600));
+        assert!(astronomy.civil_twilight.is_none());
+        assert!(astronomy.golden_hour.is_none());
+        assert!(astronomy.to_string().contains("Civil: N/D"));
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+pub static CACHE_TTL: u64 = 600;
+
+pub static LOCATION_CACHE_TTL: u64 = 86400;
+
+const SECRET_PARAMS: [&str; 1] = ["appid"];
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+struct CacheMeta {
+    expires: Option<u64>,
+    last_modified: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CacheEntry {
+    pub body: String,
+    pub last_modified: Option<String>,
+    pub fresh: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct Cache {
+    dir: PathBuf,
+    ttl: Duration,
+    refresh: bool,
+}
+
+impl Cache {
+    pub fn new(dir: PathBuf, ttl: Duration) -> Self {
+        Self {
+            dir,
+            ttl,
+            refresh: false,
+        }
+    }
+
+    pub fn set_refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    pub fn get(&self, url: &str) -> Option<String> {
+        self.entry(url)
+            .filter(|entry| entry.fresh)
+            .map(|entry| entry.body)
+    }
+
+    pub fn entry(&self, url: &str) -> Option<CacheEntry> {
+        let path = self.path(url);
+        let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok()?;
+        let body = fs::read_to_string(path).ok()?;
+        let meta: CacheMeta = fs::read_to_string(self.path(url).with_extension("meta.json"))
+            .ok()
+            .and_then(|meta| serde_json::from_str(&meta).ok())
+            .unwrap_or_default();
+        let now = SystemTime::now();
+        let fresh = match meta.expires {
+            Some(expires) => now < UNIX_EPOCH + Duration::from_secs(expires),
+            None => now.duration_since(modified).unwrap_or_default() < self.ttl,
+        };
+        Some(CacheEntry {
+            body,
+            last_modified: meta.last_modified,
+            fresh: fresh && !self.refresh,
+        })
+    }
+
+    pub fn put(&self, url: &str, body: &str) {
+        self.put_with_validators(url, body, None, None);
+    }
+
+    pub fn put_with_validators(
+        &self,
+        url: &str,
+        body: &str,
+        expires: Option<SystemTime>,
+        last_modified: Option<&str>,
+    ) {
+        if fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+        let path = self.path(url);
+        let _ = fs::write(&path, body);
+        let meta_path = path.with_extension("meta.json");
+        if expires.is_none() && last_modified.is_none() {
+            let _ = fs::remove_file(meta_path);
+            return;
+        }
+        let meta = CacheMeta {
+            expires: expires.and_then(|expires| {
+                expires
+                    .duration_since(UNIX_EPOCH)
+                    .ok()
+                    .map(|expires| expires.as_secs())
+            }),
+            last_modified: last_modified.map(ToString::to_string),
+        };
+        if let Ok(meta) = serde_json::to_string(&meta) {
+            let _ = fs::write(meta_path, meta);
+        }
+    }
+
+    pub fn key(url: &str) -> String {
+        match url.split_once('?') {
+            Some((base, query)) => {
+                let params: Vec<_> = query
+                    .split('&')
+                    .filter(|param| {
+                        let name = param.split('=').next().unwrap_or_default();
+                        !SECRET_PARAMS.contains(&name)
+                    })
+                    .collect();
+                if params.is_empty() {
+                    base.to_string()
+                } else {
+                    format!("{}?{}", base, params.join("&"))
+                }
+            }
+            None => url.to_string(),
+        }
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        let hash = Self::key(url)
+            .bytes()
+            .fold(0xcbf29ce484222325u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+        self.dir.join(format!("{:016x}.json", hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs, process,
+        time::{Duration, SystemTime},
+    };
+
+    use super::{Cache, CacheEntry};
+
+    #[test]
+    fn cache_key() {
+        assert_eq!(
+            Cache::key("http://host/weather?lat=1&lon=2&units=metric&appid=secret"),
+            "http://host/weather?lat=1&lon=2&units=metric"
+        );
+        assert_eq!(
+            Cache::key("http://host/direct?appid=secret&q=monteiro"),
+            "http://host/direct?q=monteiro"
+        );
+        assert_eq!(
+            Cache::key("http://host/json?appid=secret"),
+            "http://host/json"
+        );
+        assert_eq!(Cache::key("http://host/json/"), "http://host/json/");
+    }
+
+    #[test]
+    fn cache_get_put() {
+        let dir = env::temp_dir().join(format!("wethr-cache-{}", process::id()));
+        let url = "http://host/weather?lat=1&appid=secret";
+        let cache = Cache::new(dir.clone(), Duration::from_secs(60));
+        assert_eq!(cache.get(url), None);
+        cache.put(url, "{}");
+        assert_eq!(cache.get(url), Some("{}".to_string()));
+        assert_eq!(
+            cache.get("http://host/weather?lat=1&appid=other"),
+            Some("{}".to_string())
+        );
+        assert_eq!(cache.get("http://host/weather?lat=2&appid=secret"), None);
+
+        let cache = Cache::new(dir.clone(), Duration::from_secs(60)).set_refresh(true);
+        assert_eq!(cache.get(url), None);
+        let cache = Cache::new(dir.clone(), Duration::from_secs(0));
+        assert_eq!(cache.get(url), None);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn cache_validators() {
+        let dir = env::temp_dir().join(format!("wethr-cache-validators-{}", process::id()));
+        let url = "http://host/compact?lat=1";
+        let cache = Cache::new(dir.clone(), Duration::from_secs(0));
+        let last_modified = "Tue, 14 Sep 2021 11:30:00 GMT";
+        cache.put_with_validators(
+            url,
+            "{}",
+            Some(SystemTime::now() + Duration::from_secs(60)),
+            Some(last_modified),
+        );
+        assert_eq!(cache.get(url), Some("{}".to_string()));
+
+        cache.put_with_validators(
+            url,
+            "{}",
+            Some(SystemTime::now() - Duration::from_secs(60)),
+            Some(last_modified),
+        );
+        assert_eq!(cache.get(url), None);
+        assert_eq!(
+            cache.entry(url),
+            Some(CacheEntry {
+                body: "{}".to_string(),
+                last_modified: Some(last_modified.to_string()),
+                fresh: false,
+            })
+        );
+
+        cache.put(url, "[]");
+        assert_eq!(
+            cache.entry(url),
+            Some(CacheEntry {
+                body: "[]".to_string(),
+                last_modified: None,
+                fresh: false,
+            })
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
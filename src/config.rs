@@ -0,0 +1,433 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs, io,
+    path::PathBuf,
+    result,
+};
+
+use thiserror::Error;
+
+use crate::{
+    api_key::ApiKey,
+    args::{Args, ArgsError},
+    cache::{CACHE_TTL, LOCATION_CACHE_TTL},
+    client::{CLIENT_CONNECT_TIMEOUT, CLIENT_TIMEOUT},
+    consts::CONFIG_FILE,
+    location::provider::LocationProvider,
+    options::Options,
+    output::Output,
+    paths,
+    time_zone::TimeZone,
+    units::UnitSystem,
+    weather::provider::WeatherProviderKind,
+};
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Unable to read configuration file {0}: {1}")]
+    Io(PathBuf, io::Error),
+    #[error("Invalid configuration file {0}: {1}")]
+    Parse(PathBuf, toml::de::Error),
+    #[error("Invalid configuration file {0}: {1}")]
+    Invalid(PathBuf, ArgsError),
+    #[error(
+        "--no-cache can't be used with the {0} weather provider, its terms require conditional \
+        requests; use --refresh to skip cached responses instead"
+    )]
+    NoCache(WeatherProviderKind),
+}
+
+pub type ConfigResult<T> = result::Result<T, ConfigError>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigSource {
+    CommandLine,
+    File,
+    Default,
+}
+
+impl Display for ConfigSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::CommandLine => write!(f, "command line"),
+            Self::File => write!(f, "config file"),
+            Self::Default => write!(f, "default"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    pub path: Option<PathBuf>,
+    pub file: Options,
+    pub args: Options,
+}
+
+impl Config {
+    pub fn load(args: Options) -> ConfigResult<Self> {
+        Self::load_from(paths::config_file(CONFIG_FILE), args)
+    }
+
+    pub fn load_from(path: Option<PathBuf>, args: Options) -> ConfigResult<Self> {
+        let file = match &path {
+            Some(path) => match fs::read_to_string(path) {
+                Ok(text) => toml::from_str(&text)
+                    .map_err(|error| ConfigError::Parse(path.clone(), error))?,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => Options::default(),
+                Err(error) => return Err(ConfigError::Io(path.clone(), error)),
+            },
+            None => Options::default(),
+        };
+        let config = Self { path, file, args };
+        if let Some(path) = &config.path {
+            Args::validate(&config.options())
+                .map_err(|error| ConfigError::Invalid(path.clone(), error))?;
+        }
+        Ok(config)
+    }
+
+    pub fn options(&self) -> Options {
+        self.args.clone().merge(self.file.clone())
+    }
+
+    pub fn show(&self, api_key: &ApiKey) -> String {
+        let quote = |value: String| toml::Value::String(value).to_string();
+        let list = |providers: &[LocationProvider]| {
+            let providers: Vec<String> = providers
+                .iter()
+                .map(|provider| quote(provider.to_string()))
+                .collect();
+            format!("[{}]", providers.join(", "))
+        };
+        let mut lines = vec![match &self.path {
+            Some(path) if path.exists() => format!("# Configuration file: {}", path.display()),
+            Some(path) => format!("# Configuration file: {} (not found)", path.display()),
+            None => "# Configuration file: none".to_string(),
+        }];
+        let mut entry =
+            |name: &str, args: Option<String>, file: Option<String>, default: Option<String>| {
+                let value = match (args, file, default) {
+                    (Some(value), _, _) => Some((value, ConfigSource::CommandLine)),
+                    (None, Some(value), _) => Some((value, ConfigSource::File)),
+                    (None, None, Some(value)) => Some((value, ConfigSource::Default)),
+                    (None, None, None) => None,
+                };
+                if let Some((value, source)) = value {
+                    lines.push(format!("{} = {}  # {}", name, value, source));
+                }
+            };
+        let (args, file) = (&self.args, &self.file);
+        entry(
+            "units",
+            args.units.map(|units| quote(units.to_string())),
+            file.units.map(|units| quote(units.to_string())),
+            Some(quote(UnitSystem::default().to_string())),
+        );
+        entry(
+            "temperature_unit",
+            args.temperature_unit.map(|unit| quote(unit.to_string())),
+            file.temperature_unit.map(|unit| quote(unit.to_string())),
+            None,
+        );
+        entry(
+            "wind_unit",
+            args.wind_unit.map(|unit| quote(unit.to_string())),
+            file.wind_unit.map(|unit| quote(unit.to_string())),
+            None,
+        );
+        entry(
+            "pressure_unit",
+            args.pressure_unit.map(|unit| quote(unit.to_string())),
+            file.pressure_unit.map(|unit| quote(unit.to_string())),
+            None,
+        );
+        entry(
+            "precipitation_unit",
+            args.precipitation_unit.map(|unit| quote(unit.to_string())),
+            file.precipitation_unit.map(|unit| quote(unit.to_string())),
+            None,
+        );
+        entry(
+            "connect_timeout",
+            args.connect_timeout.map(|timeout| timeout.to_string()),
+            file.connect_timeout.map(|timeout| timeout.to_string()),
+            Some(CLIENT_CONNECT_TIMEOUT.to_string()),
+        );
+        entry(
+            "timeout",
+            args.timeout.map(|timeout| timeout.to_string()),
+            file.timeout.map(|timeout| timeout.to_string()),
+            Some(CLIENT_TIMEOUT.to_string()),
+        );
+        entry(
+            "proxy",
+            args.proxy.clone().map(quote),
+            file.proxy.clone().map(quote),
+            None,
+        );
+        entry(
+            "cache_ttl",
+            args.cache_ttl.map(|ttl| ttl.to_string()),
+            file.cache_ttl.map(|ttl| ttl.to_string()),
+            Some(CACHE_TTL.to_string()),
+        );
+        entry(
+            "location_cache_ttl",
+            args.location_cache_ttl.map(|ttl| ttl.to_string()),
+            file.location_cache_ttl.map(|ttl| ttl.to_string()),
+            Some(LOCATION_CACHE_TTL.to_string()),
+        );
+        entry(
+            "no_cache",
+            args.no_cache.map(|no_cache| no_cache.to_string()),
+            file.no_cache.map(|no_cache| no_cache.to_string()),
+            Some(false.to_string()),
+        );
+        entry(
+            "fallback",
+            args.fallback.map(|fallback| fallback.to_string()),
+            file.fallback.map(|fallback| fallback.to_string()),
+            Some(false.to_string()),
+        );
+        entry(
+            "query",
+            args.query.clone().map(quote),
+            file.query.clone().map(quote),
+            None,
+        );
+        entry(
+            "zip",
+            args.zip.clone().map(quote),
+            file.zip.clone().map(quote),
+            None,
+        );
+        entry(
+            "first",
+            args.first.map(|first| first.to_string()),
+            file.first.map(|first| first.to_string()),
+            Some(false.to_string()),
+        );
+        entry(
+            "location_provider",
+            args.location_provider
+                .map(|provider| quote(provider.to_string())),
+            file.location_provider
+                .map(|provider| quote(provider.to_string())),
+            Some(quote(LocationProvider::default().to_string())),
+        );
+        entry(
+            "location_providers",
+            args.location_providers.as_deref().map(list),
+            file.location_providers.as_deref().map(list),
+            None,
+        );
+        entry(
+            "weather_provider",
+            args.weather_provider
+                .map(|provider| quote(provider.to_string())),
+            file.weather_provider
+                .map(|provider| quote(provider.to_string())),
+            Some(quote(WeatherProviderKind::default().to_string())),
+        );
+        entry(
+            "forecast",
+            args.forecast.map(|days| days.to_string()),
+            file.forecast.map(|days| days.to_string()),
+            None,
+        );
+        entry(
+            "hourly",
+            args.hourly.map(|hours| hours.to_string()),
+            file.hourly.map(|hours| hours.to_string()),
+            None,
+        );
+        entry(
+            "air",
+            args.air.map(|air| air.to_string()),
+            file.air.map(|air| air.to_string()),
+            Some(false.to_string()),
+        );
+        entry(
+            "alerts",
+            args.alerts.map(|alerts| alerts.to_string()),
+            file.alerts.map(|alerts| alerts.to_string()),
+            Some(false.to_string()),
+        );
+        entry(
+            "alerts_only",
+            args.alerts_only.map(|alerts_only| alerts_only.to_string()),
+            file.alerts_only.map(|alerts_only| alerts_only.to_string()),
+            Some(false.to_string()),
+        );
+        entry(
+            "full_info",
+            args.full_info.map(|full_info| full_info.to_string()),
+            file.full_info.map(|full_info| full_info.to_string()),
+            Some(false.to_string()),
+        );
+        entry(
+            "template",
+            args.template.clone().map(quote),
+            file.template.clone().map(quote),
+            None,
+        );
+        entry(
+            "time_zone",
+            args.time_zone.map(|time_zone| quote(time_zone.to_string())),
+            file.time_zone.map(|time_zone| quote(time_zone.to_string())),
+            Some(quote(TimeZone::default().to_string())),
+        );
+        entry(
+            "time_format",
+            args.time_format.clone().map(quote),
+            file.time_format.clone().map(quote),
+            None,
+        );
+        entry(
+            "output",
+            args.output.map(|output| quote(output.to_string())),
+            file.output.map(|output| quote(output.to_string())),
+            Some(quote(Output::default().to_string())),
+        );
+        entry(
+            "silent",
+            args.silent.map(|silent| silent.to_string()),
+            file.silent.map(|silent| silent.to_string()),
+            Some(false.to_string()),
+        );
+        let masked: String = api_key.value.chars().take(4).collect();
+        lines.push(format!(
+            "# api_key = {}  # {}",
+            quote(format!("{}...", masked)),
+            api_key.source
+        ));
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{Config, ConfigError};
+    use crate::{
+        api_key::ApiKey,
+        args::ArgsError,
+        location::provider::LocationProvider,
+        options::Options,
+        output::Output,
+        units::{PressureUnit, SpeedUnit, UnitSystem},
+    };
+
+    #[test]
+    fn config_load() {
+        let path = env::temp_dir().join(format!("wethr-config-{}.toml", process::id()));
+        fs::write(
+            &path,
+            "units = \"F\"\npressure_unit = \"mmHg\"\ntimeout = 10\nfull_info = true\noutput = \"json\"\n",
+        )
+        .unwrap();
+        let args = Options {
+            units: Some(UnitSystem::Metric),
+            ..Default::default()
+        };
+        let config = Config::load_from(Some(path.clone()), args).unwrap();
+        assert_eq!(config.file.units, Some(UnitSystem::Imperial));
+        let opts = config.options();
+        assert_eq!(opts.units, Some(UnitSystem::Metric));
+        assert_eq!(opts.pressure_unit, Some(PressureUnit::MillimetersOfMercury));
+        assert_eq!(opts.timeout, Some(10));
+        assert_eq!(opts.full_info, Some(true));
+        assert_eq!(opts.output, Some(Output::Json));
+        assert_eq!(opts.silent, None);
+
+        fs::write(&path, "units = \"K\"\n").unwrap();
+        let error = Config::load_from(Some(path.clone()), Options::default()).unwrap_err();
+        assert!(matches!(error, ConfigError::Parse(_, _)));
+        fs::write(&path, "unknown = 1\n").unwrap();
+        let error = Config::load_from(Some(path.clone()), Options::default()).unwrap_err();
+        assert!(matches!(error, ConfigError::Parse(_, _)));
+
+        fs::remove_file(&path).unwrap();
+        let config = Config::load_from(Some(path), Options::default()).unwrap();
+        assert_eq!(config.file.units, None);
+        let config = Config::load_from(None, Options::default()).unwrap();
+        assert_eq!(config.file.units, None);
+    }
+
+    #[test]
+    fn config_load_validate() {
+        let path = env::temp_dir().join(format!("wethr-config-validate-{}.toml", process::id()));
+        fs::write(&path, "forecast = 10\n").unwrap();
+        let error = Config::load_from(Some(path.clone()), Options::default()).unwrap_err();
+        assert!(matches!(
+            error,
+            ConfigError::Invalid(_, ArgsError::ForecastDays(_, 5))
+        ));
+        let args = Options {
+            forecast: Some(3),
+            ..Default::default()
+        };
+        assert!(Config::load_from(Some(path.clone()), args).is_ok());
+        fs::write(&path, "hourly = 0\n").unwrap();
+        let error = Config::load_from(Some(path.clone()), Options::default()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Invalid configuration file {}: Invalid forecast hours `0`, expected 1 to 120",
+                path.display()
+            )
+        );
+
+        fs::write(
+            &path,
+            "no_cache = true\nweather_provider = \"met-norway\"\n",
+        )
+        .unwrap();
+        assert!(Config::load_from(Some(path.clone()), Options::default()).is_ok());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn config_show() {
+        let config = Config {
+            path: None,
+            file: Options {
+                units: Some(UnitSystem::Imperial),
+                wind_unit: Some(SpeedUnit::Knots),
+                timeout: Some(10),
+                template: Some("{city}".to_string()),
+                location_providers: Some(vec![LocationProvider::Ipapi, LocationProvider::IpApi]),
+                ..Default::default()
+            },
+            args: Options {
+                timeout: Some(20),
+                silent: Some(true),
+                ..Default::default()
+            },
+        };
+        let text = "# Configuration file: none
+units = \"imperial\"  # config file
+wind_unit = \"knots\"  # config file
+connect_timeout = 5  # default
+timeout = 20  # command line
+cache_ttl = 600  # default
+location_cache_ttl = 86400  # default
+no_cache = false  # default
+fallback = false  # default
+first = false  # default
+location_provider = \"ip-api\"  # default
+location_providers = [\"ipapi\", \"ip-api\"]  # config file
+weather_provider = \"openweathermap\"  # default
+air = false  # default
+alerts = false  # default
+alerts_only = false  # default
+full_info = false  # default
+template = \"{city}\"  # config file
+time_zone = \"location\"  # default
+output = \"text\"  # default
+silent = true  # command line
+# api_key = \"315b...\"  # built-in";
+        assert_eq!(config.show(&ApiKey::default()), text);
+    }
+}
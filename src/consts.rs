@@ -1,5 +1,15 @@
-pub const PROGRAM_NAME: &'static str = env!("CARGO_PKG_NAME");
+pub const PROGRAM_NAME: &str = env!("CARGO_PKG_NAME");
 
-pub const PROGRAM_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+pub const PROGRAM_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const PROGRAM_HOMEPAGE: &str = env!("CARGO_PKG_HOMEPAGE");
 
 pub const TOKEN: &str = "315bfb21a64943c67a92e2da0022fdbe";
+
+pub const API_KEY_ENV: &str = "WETHR_API_KEY";
+
+pub const API_KEY_FILE: &str = "api_key";
+
+pub const CONFIG_FILE: &str = "config.toml";
+
+pub const SNAPSHOT_FILE: &str = "snapshot.json";
@@ -1,36 +1,408 @@
 use std::{
-    fmt::{Display, Formatter, Result},
+    fmt::{Display, Formatter, Result, Write},
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::time_zone::TimeZone;
+
+const SECONDS_PER_DAY: u64 = 86400;
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DateTimeFormat {
+    pub time_zone: TimeZone,
+    pub pattern: Option<String>,
+}
+
+impl DateTimeFormat {
+    pub fn new(time_zone: TimeZone, pattern: Option<String>) -> Self {
+        Self { time_zone, pattern }
+    }
+}
+
 #[derive(Clone, Debug)]
-pub struct DateTime(SystemTime);
+pub struct DateTime {
+    time: SystemTime,
+    offset: i32,
+}
 
 impl DateTime {
     pub fn from_unix(value: u64) -> Self {
         Self {
-            0: UNIX_EPOCH + Duration::from_secs(value),
+            time: UNIX_EPOCH + Duration::from_secs(value),
+            offset: 0,
+        }
+    }
+
+    pub fn now() -> Self {
+        Self {
+            time: SystemTime::now(),
+            offset: 0,
+        }
+    }
+
+    pub fn set_offset(mut self, offset: i32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    pub fn age(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(self.time)
+            .unwrap_or_default()
+    }
+
+    pub fn unix(&self) -> u64 {
+        self.time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+
+    pub fn days(&self) -> u64 {
+        self.unix() / SECONDS_PER_DAY
+    }
+
+    pub fn local_days(&self) -> i64 {
+        (self.unix() as i64 + self.offset as i64).div_euclid(SECONDS_PER_DAY as i64)
+    }
+
+    pub fn midnight(&self) -> Self {
+        let local = self.local_days() * SECONDS_PER_DAY as i64 - self.offset as i64;
+        Self::from_unix(local.max(0) as u64).set_offset(self.offset)
+    }
+
+    pub fn with_time_zone(&self, time_zone: TimeZone) -> Self {
+        self.clone().set_offset(time_zone.offset(self))
+    }
+
+    pub fn weekday(&self) -> &'static str {
+        &WEEKDAYS[Fields::new(self).weekday][..3]
+    }
+
+    pub fn date(&self) -> String {
+        self.strftime("%F")
+    }
+
+    pub fn time(&self) -> String {
+        self.strftime("%R")
+    }
+
+    pub fn format(&self, format: &DateTimeFormat) -> String {
+        let date_time = self.with_time_zone(format.time_zone);
+        match &format.pattern {
+            Some(pattern) => date_time.strftime(pattern),
+            None => date_time.to_string(),
+        }
+    }
+
+    pub fn strftime(&self, pattern: &str) -> String {
+        let fields = Fields::new(self);
+        let mut output = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                output.push(c);
+                continue;
+            }
+            let _ = match chars.next() {
+                Some('Y') => write!(output, "{}", fields.year),
+                Some('y') => write!(output, "{:02}", fields.year.rem_euclid(100)),
+                Some('m') => write!(output, "{:02}", fields.month),
+                Some('d') => write!(output, "{:02}", fields.day),
+                Some('e') => write!(output, "{:2}", fields.day),
+                Some('j') => write!(output, "{:03}", fields.year_day),
+                Some('H') => write!(output, "{:02}", fields.hour),
+                Some('I') => write!(output, "{:02}", (fields.hour + 11) % 12 + 1),
+                Some('M') => write!(output, "{:02}", fields.minute),
+                Some('S') => write!(output, "{:02}", fields.second),
+                Some('p') => write!(output, "{}", if fields.hour < 12 { "AM" } else { "PM" }),
+                Some('a') => write!(output, "{}", &WEEKDAYS[fields.weekday][..3]),
+                Some('A') => write!(output, "{}", WEEKDAYS[fields.weekday]),
+                Some('b') => write!(output, "{}", &MONTHS[fields.month as usize - 1][..3]),
+                Some('B') => write!(output, "{}", MONTHS[fields.month as usize - 1]),
+                Some('F') => write!(
+                    output,
+                    "{}-{:02}-{:02}",
+                    fields.year, fields.month, fields.day
+                ),
+                Some('T') => write!(
+                    output,
+                    "{:02}:{:02}:{:02}",
+                    fields.hour, fields.minute, fields.second
+                ),
+                Some('R') => write!(output, "{:02}:{:02}", fields.hour, fields.minute),
+                Some('z') => write!(output, "{}", format_offset(self.offset, "")),
+                Some('Z') => match self.offset {
+                    0 => write!(output, "UTC"),
+                    offset => write!(output, "UTC{}", format_offset(offset, ":")),
+                },
+                Some('s') => write!(output, "{}", self.unix()),
+                Some('%') => write!(output, "%"),
+                Some(other) => write!(output, "%{}", other),
+                None => write!(output, "%"),
+            };
+        }
+        output
+    }
+}
+
+fn format_offset(offset: i32, separator: &str) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let minutes = offset.abs() / 60;
+    format!(
+        "{}{:02}{}{:02}",
+        sign,
+        minutes / 60,
+        separator,
+        minutes % 60
+    )
+}
+
+fn parse_offset(value: &str) -> Option<i32> {
+    let sign = match value.get(..1)? {
+        "+" => 1,
+        "-" => -1,
+        _ => return None,
+    };
+    if value.len() != 6 || value.get(3..4)? != ":" {
+        return None;
+    }
+    let hours: i32 = value.get(1..3)?.parse().ok()?;
+    let minutes: i32 = value.get(4..6)?.parse().ok()?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+struct Fields {
+    year: i64,
+    month: u32,
+    day: u32,
+    year_day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    weekday: usize,
+}
+
+impl Fields {
+    // Civil date from days since the epoch, after Howard Hinnant's `civil_from_days`.
+    fn new(date_time: &DateTime) -> Self {
+        let local = date_time.unix() as i64 + date_time.offset as i64;
+        let days = local.div_euclid(SECONDS_PER_DAY as i64);
+        let seconds = local.rem_euclid(SECONDS_PER_DAY as i64) as u32;
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let day_of_era = z.rem_euclid(146097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        // The era starts in March, so January and February close the previous year.
+        let year_day = if month <= 2 {
+            day_of_year - 306
+        } else {
+            day_of_year + 59 + if leap { 1 } else { 0 }
+        } as u32
+            + 1;
+        Self {
+            year,
+            month,
+            day,
+            year_day,
+            hour: seconds / 3600,
+            minute: seconds / 60 % 60,
+            second: seconds % 60,
+            // 1970-01-01 was a Thursday.
+            weekday: (days + 4).rem_euclid(7) as usize,
         }
     }
 }
 
 impl Display for DateTime {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(
-            f,
-            "{}",
-            humantime::format_rfc3339_seconds(self.0).to_string()
-        )
+        write!(f, "{}", self.strftime("%FT%T"))?;
+        match self.offset {
+            0 => write!(f, "Z"),
+            offset => write!(f, "{}", format_offset(offset, ":")),
+        }
+    }
+}
+
+impl Serialize for DateTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let split = value.len().saturating_sub(6);
+        let (value, offset) = match value.get(split..).and_then(parse_offset) {
+            Some(offset) => (format!("{}Z", &value[..split]), offset),
+            None => (value, 0),
+        };
+        let time = humantime::parse_rfc3339(&value).map_err(de::Error::custom)?;
+        let local = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        Ok(Self::from_unix((local - offset as i64).max(0) as u64).set_offset(offset))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::DateTime;
+    use super::{DateTime, DateTimeFormat};
+    use crate::time_zone::TimeZone;
 
     #[test]
     fn format_unix_datetime() {
         let datetime = DateTime::from_unix(1631620646);
         assert_eq!(datetime.to_string(), "2021-09-14T11:57:26Z");
+        let datetime = datetime.set_offset(-10800);
+        assert_eq!(datetime.to_string(), "2021-09-14T08:57:26-03:00");
+        let datetime = DateTime::from_unix(1631620646).set_offset(19800);
+        assert_eq!(datetime.to_string(), "2021-09-14T17:27:26+05:30");
+    }
+
+    #[test]
+    fn datetime_parts() {
+        let datetime = DateTime::from_unix(1631620646);
+        assert_eq!(datetime.unix(), 1631620646);
+        assert_eq!(datetime.days(), 18884);
+        assert_eq!(datetime.weekday(), "Tue");
+        assert_eq!(datetime.date(), "2021-09-14");
+        assert_eq!(datetime.time(), "11:57");
+        assert_eq!(DateTime::from_unix(0).weekday(), "Thu");
+
+        let datetime = DateTime::from_unix(1631580000).set_offset(-10800);
+        assert_eq!(datetime.weekday(), "Mon");
+        assert_eq!(datetime.date(), "2021-09-13");
+        assert_eq!(datetime.time(), "21:40");
+        assert_eq!(datetime.days(), 18884);
+        assert_eq!(datetime.local_days(), 18883);
+        assert_eq!(datetime.midnight().to_string(), "2021-09-13T00:00:00-03:00");
+
+        let datetime = DateTime::from_unix(1631580000).set_offset(32400);
+        assert_eq!(datetime.local_days(), 18884);
+        assert_eq!(datetime.midnight().to_string(), "2021-09-14T00:00:00+09:00");
+        assert_eq!(
+            datetime.with_time_zone(TimeZone::Utc).to_string(),
+            "2021-09-14T00:40:00Z"
+        );
+    }
+
+    #[test]
+    fn datetime_strftime() {
+        let datetime = DateTime::from_unix(1631607769).set_offset(-10800);
+        assert_eq!(datetime.strftime("%H:%M"), "05:22");
+        assert_eq!(
+            datetime.strftime("%a, %d %b %Y %I:%M:%S %p %z"),
+            "Tue, 14 Sep 2021 05:22:49 AM -0300"
+        );
+        assert_eq!(
+            datetime.strftime("%A %e %B %y, day %j, %T %Z"),
+            "Tuesday 14 September 21, day 257, 05:22:49 UTC-03:00"
+        );
+        assert_eq!(datetime.strftime("%s %% %q %"), "1631607769 % %q %");
+        assert_eq!(
+            DateTime::from_unix(951782400).strftime("%F %j"),
+            "2000-02-29 060"
+        );
+        assert_eq!(
+            DateTime::from_unix(978220800).strftime("%F %j"),
+            "2000-12-31 366"
+        );
+        assert_eq!(
+            DateTime::from_unix(0).strftime("%F %j %Z"),
+            "1970-01-01 001 UTC"
+        );
+    }
+
+    #[test]
+    fn datetime_format() {
+        let datetime = DateTime::from_unix(1631607769).set_offset(-10800);
+        assert_eq!(
+            datetime.format(&DateTimeFormat::default()),
+            "2021-09-14T05:22:49-03:00"
+        );
+        assert_eq!(
+            datetime.format(&DateTimeFormat::new(TimeZone::Utc, None)),
+            "2021-09-14T08:22:49Z"
+        );
+        assert_eq!(
+            datetime.format(&DateTimeFormat::new(
+                TimeZone::Location,
+                Some("%H:%M".to_string())
+            )),
+            "05:22"
+        );
+    }
+
+    #[test]
+    fn serialize_datetime() {
+        let datetime = DateTime::from_unix(1631620646);
+        assert_eq!(
+            serde_json::to_string(&datetime).unwrap(),
+            "\"2021-09-14T11:57:26Z\""
+        );
+        let datetime: DateTime = serde_json::from_str("\"2021-09-14T11:57:26Z\"").unwrap();
+        assert_eq!(datetime.unix(), 1631620646);
+        let datetime: DateTime = serde_json::from_str("\"2021-09-14T11:57:26+00:00\"").unwrap();
+        assert_eq!(datetime.unix(), 1631620646);
+        assert_eq!(datetime.offset(), 0);
+        let datetime: DateTime = serde_json::from_str("\"2021-09-14T08:57:26-03:00\"").unwrap();
+        assert_eq!(datetime.unix(), 1631620646);
+        assert_eq!(datetime.offset(), -10800);
+        assert_eq!(
+            serde_json::to_string(&datetime).unwrap(),
+            "\"2021-09-14T08:57:26-03:00\""
+        );
+        assert!(serde_json::from_str::<DateTime>("\"yesterday\"").is_err());
+    }
+
+    #[test]
+    fn datetime_age() {
+        assert!(DateTime::from_unix(0).age().as_secs() > 1631620646);
+        assert_eq!(DateTime::from_unix(u32::MAX as u64 * 2).age().as_secs(), 0);
     }
 }
@@ -1,6 +1,6 @@
 #[inline]
 fn is_match(text: &str, pattern: &str) -> bool {
-    pattern.split("|").any(|d| text.starts_with(d))
+    pattern.split("|").any(|d| text.contains(d))
 }
 
 pub fn get_emoji(description: &str) -> Option<&str> {
@@ -19,7 +19,7 @@ pub fn get_emoji(description: &str) -> Option<&str> {
     if is_match(description, "thunderstorm") {
         return Some("⛈");
     }
-    if is_match(description, "snow|sleet") {
+    if is_match(description, "snow|sleet|freezing") {
         return Some("🌨");
     }
     if is_match(description, "drizzle|rain") {
@@ -46,8 +46,11 @@ mod tests {
         assert_eq!(super::get_emoji("thunderstorm"), Some("⛈"));
         assert_eq!(super::get_emoji("snow"), Some("🌨"));
         assert_eq!(super::get_emoji("sleet"), Some("🌨"));
+        assert_eq!(super::get_emoji("freezing rain"), Some("🌨"));
         assert_eq!(super::get_emoji("drizzle"), Some("🌧"));
         assert_eq!(super::get_emoji("rain"), Some("🌧"));
+        assert_eq!(super::get_emoji("light rain"), Some("🌧"));
+        assert_eq!(super::get_emoji("thunderstorm with light rain"), Some("⛈"));
         assert_eq!(super::get_emoji("mist"), Some("🌫"));
         assert_eq!(super::get_emoji("smoke"), Some("🌫"));
         assert_eq!(super::get_emoji("haze"), Some("🌫"));
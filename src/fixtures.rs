@@ -0,0 +1,66 @@
+use crate::{
+    datetime::DateTime,
+    forecast::model::ForecastEntry,
+    location::model::{Coordinates, Location},
+    weather::model::{Weather, Wind},
+};
+
+pub fn location() -> Location {
+    Location {
+        city: "Monteiro".to_string(),
+        region: None,
+        country: "Brazil".to_string(),
+        country_code: None,
+        postal_code: None,
+        coordinates: Coordinates::new(-7.9194, -37.175),
+    }
+}
+
+pub fn wind() -> Wind {
+    Wind {
+        speed: 4.72,
+        degrees: 115,
+        gust: 6.14,
+    }
+}
+
+pub fn weather() -> Weather {
+    Weather {
+        temperature: 25.8,
+        icon: "☀️".to_string(),
+        description: "Scattered clouds".to_string(),
+        feels_like: 25.87,
+        min_temperature: 25.8,
+        max_temperature: 25.8,
+        pressure: 1017.0,
+        humidity: 55,
+        sea_level: Some(1017.0),
+        ground_level: None,
+        wind: wind(),
+        clouds: 46,
+        date_time: DateTime::from_unix(1631620646),
+        sunrise: DateTime::from_unix(1631607769),
+        sunset: DateTime::from_unix(1631651152),
+        solar_noon: None,
+        day_length: None,
+        uv_index: None,
+        precipitation: None,
+    }
+}
+
+pub fn forecast_entry(dt: u64, min: f32, max: f32, icon: &str, description: &str) -> ForecastEntry {
+    ForecastEntry {
+        date_time: DateTime::from_unix(dt),
+        temperature: min,
+        icon: icon.to_string(),
+        description: description.to_string(),
+        feels_like: min,
+        min_temperature: min,
+        max_temperature: max,
+        pressure: 1017.0,
+        humidity: 55,
+        precipitation_probability: 0,
+        wind: wind(),
+        clouds: 46,
+    }
+}
@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::{
+    cache::Cache,
+    client::{ClientBuilder, ClientError},
+    consts::TOKEN,
+    datetime::DateTime,
+    forecast::model::{Forecast, ForecastEntry},
+    location::model::Coordinates,
+    weather::openweathermap::{WeatherClouds, WeatherMain, WeatherMap, WeatherWindMap},
+};
+
+pub const URL_FORECAST: &str = "http://api.openweathermap.org/data/2.5/forecast";
+
+pub static FORECAST_DAYS: u8 = 5;
+
+pub static FORECAST_HOURS: u8 = 24;
+
+#[derive(Debug, Error)]
+pub enum ForecastClientError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+}
+
+impl ForecastClientError {
+    pub fn is_offline(&self) -> bool {
+        matches!(self, Self::Client(error) if error.is_offline())
+    }
+}
+
+#[derive(Debug)]
+pub struct ForecastClient {
+    inner: ClientBuilder,
+    api_key: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct ForecastEntryResponse {
+    dt: u64,
+    weather: Option<Vec<WeatherMap>>,
+    main: Option<WeatherMain>,
+    wind: Option<WeatherWindMap>,
+    clouds: Option<WeatherClouds>,
+    pop: Option<f32>,
+}
+
+impl From<ForecastEntryResponse> for ForecastEntry {
+    fn from(response: ForecastEntryResponse) -> Self {
+        let weather = response
+            .weather
+            .unwrap_or_default()
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        let main = response.main.unwrap_or_default();
+        Self {
+            date_time: DateTime::from_unix(response.dt),
+            temperature: main.temp,
+            icon: weather.icon(),
+            description: weather.format_description(),
+            feels_like: main.feels_like,
+            min_temperature: main.temp_min,
+            max_temperature: main.temp_max,
+            pressure: main.pressure,
+            humidity: main.humidity,
+            precipitation_probability: (response.pop.unwrap_or_default() * 100.0).round() as i32,
+            wind: response.wind.unwrap_or_default().into(),
+            clouds: response.clouds.unwrap_or_default().all,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct ForecastCity {
+    timezone: Option<i32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct ForecastResponse {
+    list: Vec<ForecastEntryResponse>,
+    city: Option<ForecastCity>,
+}
+
+impl From<ForecastResponse> for Forecast {
+    fn from(response: ForecastResponse) -> Self {
+        let offset = response
+            .city
+            .unwrap_or_default()
+            .timezone
+            .unwrap_or_default();
+        Self {
+            entries: response
+                .list
+                .into_iter()
+                .map(|entry| {
+                    let mut entry = ForecastEntry::from(entry);
+                    entry.date_time = entry.date_time.set_offset(offset);
+                    entry
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Default for ForecastClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ForecastClient {
+    pub fn new() -> Self {
+        Self {
+            inner: ClientBuilder::new(),
+            api_key: TOKEN.to_string(),
+        }
+    }
+
+    pub fn set_connect_timeout(self, timeout: Duration) -> Self {
+        self.with_inner(|inner| inner.set_connect_timeout(timeout))
+    }
+
+    pub fn set_timeout(self, timeout: Duration) -> Self {
+        self.with_inner(|inner| inner.set_timeout(timeout))
+    }
+
+    pub fn set_proxy(self, proxy: Option<&str>) -> Self {
+        self.with_inner(|inner| inner.set_proxy(proxy))
+    }
+
+    pub fn set_cache(self, cache: Option<Cache>) -> Self {
+        self.with_inner(|inner| inner.set_cache(cache))
+    }
+
+    pub fn set_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = api_key.to_string();
+        self
+    }
+
+    pub async fn get(self, coordinates: &Coordinates) -> Result<Forecast, ForecastClientError> {
+        let url = format!(
+            "{}?lat={lat}&lon={lon}&units=metric&appid={appid}",
+            URL_FORECAST,
+            lat = coordinates.latitude,
+            lon = coordinates.longitude,
+            appid = self.api_key
+        );
+        let res: ForecastResponse = self
+            .inner
+            .build()?
+            .get(&url)
+            .await
+            .map_err(ClientError::for_api_key)?;
+        Ok(res.into())
+    }
+
+    #[inline]
+    fn with_inner<F>(mut self, func: F) -> Self
+    where
+        F: FnOnce(ClientBuilder) -> ClientBuilder,
+    {
+        self.inner = func(self.inner);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::sleep;
+
+    use super::{Forecast, ForecastClient, ForecastResponse};
+    use crate::location::model::Coordinates;
+
+    #[tokio::test]
+    async fn forecast_client_get() {
+        sleep(Duration::from_secs(1)).await;
+        let coordinates = Coordinates::new(-7.9194, -37.175);
+        assert!(ForecastClient::new().get(&coordinates).await.is_ok());
+    }
+
+    #[test]
+    fn forecast_from_response() {
+        let json = "{
+            \"cnt\": 2,
+            \"list\": [
+              {
+                \"dt\": 1631620800,
+                \"main\": {
+                  \"temp\": 25.8,
+                  \"feels_like\": 25.87,
+                  \"temp_min\": 24.1,
+                  \"temp_max\": 25.8,
+                  \"pressure\": 1017,
+                  \"humidity\": 55
+                },
+                \"weather\": [{ \"description\": \"scattered clouds\" }],
+                \"clouds\": { \"all\": 46 },
+                \"wind\": { \"speed\": 4.72, \"deg\": 115, \"gust\": 6.14 },
+                \"pop\": 0.27
+              },
+              {
+                \"dt\": 1631631600,
+                \"main\": {
+                  \"temp\": 21.3,
+                  \"feels_like\": 21.5,
+                  \"temp_min\": 21.3,
+                  \"temp_max\": 21.3,
+                  \"pressure\": 1018,
+                  \"humidity\": 80
+                },
+                \"weather\": [{ \"description\": \"drizzle\" }],
+                \"clouds\": { \"all\": 90 },
+                \"wind\": { \"speed\": 3.1, \"deg\": 120 }
+              }
+            ],
+            \"city\": { \"name\": \"Monteiro\", \"timezone\": -10800 }
+        }";
+        let response = serde_json::from_str::<ForecastResponse>(json);
+        assert!(response.is_ok());
+        let forecast: Forecast = response.unwrap().into();
+        assert_eq!(forecast.entries.len(), 2);
+        let entry = &forecast.entries[0];
+        assert_eq!(entry.date_time.to_string(), "2021-09-14T09:00:00-03:00");
+        assert_eq!(entry.temperature, 25.8);
+        assert_eq!(entry.icon, "☁️");
+        assert_eq!(entry.description, "Scattered clouds");
+        assert_eq!(entry.feels_like, 25.87);
+        assert_eq!(entry.min_temperature, 24.1);
+        assert_eq!(entry.max_temperature, 25.8);
+        assert_eq!(entry.pressure, 1017.0);
+        assert_eq!(entry.humidity, 55);
+        assert_eq!(entry.precipitation_probability, 27);
+        assert_eq!(entry.wind.speed, 4.72);
+        assert_eq!(entry.clouds, 46);
+        let entry = &forecast.entries[1];
+        assert_eq!(entry.icon, "🌧");
+        assert_eq!(entry.description, "Drizzle");
+        assert_eq!(entry.precipitation_probability, 0);
+        assert_eq!(entry.wind.gust, 0.0);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{datetime::DateTime, time_zone::TimeZone, weather::model::Wind};
+
+#[derive(Clone, Debug)]
+pub struct ForecastEntry {
+    pub date_time: DateTime,
+    pub temperature: f32,
+    pub icon: String,
+    pub description: String,
+    pub feels_like: f32,
+    pub min_temperature: f32,
+    pub max_temperature: f32,
+    pub pressure: f32,
+    pub humidity: i32,
+    pub precipitation_probability: i32,
+    pub wind: Wind,
+    pub clouds: i32,
+}
+
+#[derive(Clone, Debug)]
+pub struct ForecastDay {
+    pub date: DateTime,
+    pub icon: String,
+    pub description: String,
+    pub min_temperature: f32,
+    pub max_temperature: f32,
+}
+
+impl ForecastDay {
+    fn from_entries(date: DateTime, entries: &[&ForecastEntry]) -> Self {
+        let prevailing = entries
+            .iter()
+            .rev()
+            .max_by_key(|entry| {
+                entries
+                    .iter()
+                    .filter(|other| other.description == entry.description)
+                    .count()
+            })
+            .unwrap();
+        Self {
+            date,
+            icon: prevailing.icon.clone(),
+            description: prevailing.description.clone(),
+            min_temperature: entries
+                .iter()
+                .map(|entry| entry.min_temperature)
+                .fold(f32::INFINITY, f32::min),
+            max_temperature: entries
+                .iter()
+                .map(|entry| entry.max_temperature)
+                .fold(f32::NEG_INFINITY, f32::max),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Forecast {
+    pub entries: Vec<ForecastEntry>,
+}
+
+impl Forecast {
+    pub fn hours(&self, hours: u64) -> &[ForecastEntry] {
+        let end = match self.entries.first() {
+            Some(first) => first
+                .date_time
+                .unix()
+                .saturating_add(hours.saturating_mul(3600)),
+            None => return &[],
+        };
+        let count = self
+            .entries
+            .iter()
+            .take_while(|entry| entry.date_time.unix() < end)
+            .count();
+        &self.entries[..count]
+    }
+
+    pub fn days(&self, time_zone: TimeZone) -> Vec<ForecastDay> {
+        let mut groups: Vec<(DateTime, Vec<&ForecastEntry>)> = Vec::new();
+        for entry in &self.entries {
+            let date_time = entry.date_time.with_time_zone(time_zone);
+            match groups.last_mut() {
+                Some((date, group)) if date.local_days() == date_time.local_days() => {
+                    group.push(entry)
+                }
+                _ => groups.push((date_time.midnight(), vec![entry])),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(date, group)| ForecastDay::from_entries(date, &group))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Forecast;
+    use crate::{fixtures::forecast_entry, time_zone::TimeZone};
+
+    #[test]
+    fn forecast_days() {
+        let forecast = Forecast {
+            entries: vec![
+                forecast_entry(1631620800, 24.1, 25.2, "☁️", "Clear sky"),
+                forecast_entry(1631631600, 26.3, 28.7, "☁️", "Scattered clouds"),
+                forecast_entry(1631642400, 22.0, 23.5, "☁️", "Scattered clouds"),
+                forecast_entry(1631664000, 19.4, 20.1, "☁️", "Light rain"),
+                forecast_entry(1631674800, 18.9, 19.2, "☁️", "Clear sky"),
+            ],
+        };
+        let days = forecast.days(TimeZone::Location);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date.date(), "2021-09-14");
+        assert_eq!(days[0].min_temperature, 22.0);
+        assert_eq!(days[0].max_temperature, 28.7);
+        assert_eq!(days[0].description, "Scattered clouds");
+        assert_eq!(days[1].date.date(), "2021-09-15");
+        assert_eq!(days[1].min_temperature, 18.9);
+        assert_eq!(days[1].max_temperature, 20.1);
+        assert_eq!(days[1].description, "Light rain");
+
+        let forecast = Forecast { entries: vec![] };
+        assert!(forecast.days(TimeZone::Location).is_empty());
+    }
+
+    #[test]
+    fn forecast_days_local_date() {
+        let forecast = Forecast {
+            entries: vec![
+                forecast_entry(1631620800, 24.1, 25.2, "☁️", "Clear sky"),
+                forecast_entry(1631631600, 26.3, 28.7, "☁️", "Scattered clouds"),
+                forecast_entry(1631642400, 22.0, 23.5, "☁️", "Scattered clouds"),
+                forecast_entry(1631664000, 19.4, 20.1, "☁️", "Light rain"),
+                forecast_entry(1631674800, 18.9, 19.2, "☁️", "Clear sky"),
+            ]
+            .into_iter()
+            .map(|mut entry| {
+                entry.date_time = entry.date_time.set_offset(-10800);
+                entry
+            })
+            .collect(),
+        };
+        let days = forecast.days(TimeZone::Location);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date.to_string(), "2021-09-14T00:00:00-03:00");
+        assert_eq!(days[0].min_temperature, 19.4);
+        assert_eq!(days[0].max_temperature, 28.7);
+        assert_eq!(days[1].date.to_string(), "2021-09-15T00:00:00-03:00");
+        assert_eq!(days[1].min_temperature, 18.9);
+        assert_eq!(days[1].description, "Clear sky");
+
+        let days = forecast.days(TimeZone::Utc);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date.to_string(), "2021-09-14T00:00:00Z");
+        assert_eq!(days[0].max_temperature, 28.7);
+        assert_eq!(days[1].min_temperature, 18.9);
+        assert_eq!(days[1].max_temperature, 20.1);
+    }
+
+    #[test]
+    fn forecast_hours() {
+        let forecast = Forecast {
+            entries: vec![
+                forecast_entry(1631620800, 24.1, 25.2, "☁️", "Clear sky"),
+                forecast_entry(1631631600, 26.3, 28.7, "☁️", "Scattered clouds"),
+                forecast_entry(1631642400, 22.0, 23.5, "☁️", "Scattered clouds"),
+            ],
+        };
+        assert_eq!(forecast.hours(0).len(), 0);
+        assert_eq!(forecast.hours(1).len(), 1);
+        assert_eq!(forecast.hours(3).len(), 1);
+        assert_eq!(forecast.hours(4).len(), 2);
+        assert_eq!(forecast.hours(24).len(), 3);
+
+        let forecast = Forecast { entries: vec![] };
+        assert!(forecast.hours(24).is_empty());
+    }
+}
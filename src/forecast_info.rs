@@ -0,0 +1,144 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{
+    datetime::{DateTime, DateTimeFormat},
+    forecast::model::Forecast,
+    location::model::Location,
+    units::Units,
+};
+
+#[derive(Clone, Debug)]
+pub struct ForecastInfo<'a> {
+    location: &'a Location,
+    forecast: &'a Forecast,
+    units: Units,
+    days: usize,
+    date_time_format: DateTimeFormat,
+}
+
+impl<'a> ForecastInfo<'a> {
+    pub fn new(location: &'a Location, forecast: &'a Forecast, units: Units) -> Self {
+        Self {
+            location,
+            forecast,
+            units,
+            days: usize::MAX,
+            date_time_format: DateTimeFormat::default(),
+        }
+    }
+
+    pub fn set_days(mut self, days: usize) -> Self {
+        self.days = days;
+        self
+    }
+
+    pub fn set_date_time_format(mut self, date_time_format: DateTimeFormat) -> Self {
+        self.date_time_format = date_time_format;
+        self
+    }
+
+    fn format_date(&self, date: &DateTime) -> String {
+        match &self.date_time_format.pattern {
+            Some(pattern) => date.strftime(pattern),
+            None => format!("{} {}", date.weekday(), date.date()),
+        }
+    }
+}
+
+impl Display for ForecastInfo<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let units = self.units.symbol();
+        let temperature = |value| self.units.temperature.convert(value);
+        write!(
+            f,
+            "{city}, {country}:",
+            city = self.location.city,
+            country = self.location.country
+        )?;
+        let days = self.forecast.days(self.date_time_format.time_zone);
+        for day in days.iter().take(self.days) {
+            write!(
+                f,
+                "\n{date}: {min}{units} / {max}{units} {emoji} {description}",
+                date = self.format_date(&day.date),
+                min = temperature(day.min_temperature),
+                max = temperature(day.max_temperature),
+                units = units,
+                emoji = day.icon,
+                description = day.description
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForecastInfo;
+    use crate::{
+        datetime::DateTimeFormat,
+        fixtures::{forecast_entry, location},
+        forecast::model::Forecast,
+        location::model::{Coordinates, Location},
+        time_zone::TimeZone,
+        units::Units,
+    };
+
+    #[test]
+    fn forecast_info_format() {
+        let location = location();
+        let forecast = Forecast {
+            entries: vec![
+                forecast_entry(1631620800, 24.1, 25.8, "☁️", "Scattered clouds"),
+                forecast_entry(1631631600, 26.3, 28.7, "☁️", "Scattered clouds"),
+                forecast_entry(1631664000, 19.4, 20.1, "🌧", "Light rain"),
+            ],
+        };
+        let info = ForecastInfo::new(&location, &forecast, Units::metric());
+        let text = "Monteiro, Brazil:
+Tue 2021-09-14: 24.1C / 28.7C ☁\u{fe0f} Scattered clouds
+Wed 2021-09-15: 19.4C / 20.1C 🌧 Light rain";
+        assert_eq!(info.to_string(), text);
+
+        let info = ForecastInfo::new(&location, &forecast, Units::imperial()).set_days(1);
+        let text = "Monteiro, Brazil:
+Tue 2021-09-14: 75.38F / 83.66F ☁\u{fe0f} Scattered clouds";
+        assert_eq!(info.to_string(), text);
+    }
+
+    #[test]
+    fn forecast_info_time_zone() {
+        let location = Location {
+            city: "Tokyo".to_string(),
+            country: "Japan".to_string(),
+            coordinates: Coordinates::new(35.6895, 139.6917),
+            ..location()
+        };
+        let forecast = Forecast {
+            entries: vec![
+                forecast_entry(1631620800, 24.1, 25.8, "☁️", "Scattered clouds"),
+                forecast_entry(1631631600, 26.3, 28.7, "☁️", "Scattered clouds"),
+                forecast_entry(1631664000, 19.4, 20.1, "🌧", "Light rain"),
+            ]
+            .into_iter()
+            .map(|mut entry| {
+                entry.date_time = entry.date_time.set_offset(32400);
+                entry
+            })
+            .collect(),
+        };
+        let info = ForecastInfo::new(&location, &forecast, Units::metric());
+        let text = "Tokyo, Japan:
+Tue 2021-09-14: 24.1C / 25.8C ☁\u{fe0f} Scattered clouds
+Wed 2021-09-15: 19.4C / 28.7C ☁\u{fe0f} Scattered clouds";
+        assert_eq!(info.to_string(), text);
+
+        let info = ForecastInfo::new(&location, &forecast, Units::metric()).set_date_time_format(
+            DateTimeFormat::new(TimeZone::Utc, Some("%d %b".to_string())),
+        );
+        let text = "Tokyo, Japan:
+14 Sep: 24.1C / 28.7C ☁\u{fe0f} Scattered clouds
+15 Sep: 19.4C / 20.1C 🌧 Light rain";
+        assert_eq!(info.to_string(), text);
+    }
+}
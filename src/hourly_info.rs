@@ -0,0 +1,146 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{
+    datetime::{DateTime, DateTimeFormat},
+    forecast::{client::FORECAST_HOURS, model::Forecast},
+    location::model::Location,
+    units::Units,
+};
+
+#[derive(Clone, Debug)]
+pub struct HourlyInfo<'a> {
+    location: &'a Location,
+    forecast: &'a Forecast,
+    units: Units,
+    hours: u64,
+    date_time_format: DateTimeFormat,
+}
+
+impl<'a> HourlyInfo<'a> {
+    pub fn new(location: &'a Location, forecast: &'a Forecast, units: Units) -> Self {
+        Self {
+            location,
+            forecast,
+            units,
+            hours: FORECAST_HOURS as u64,
+            date_time_format: DateTimeFormat::default(),
+        }
+    }
+
+    pub fn set_hours(mut self, hours: u64) -> Self {
+        self.hours = hours;
+        self
+    }
+
+    pub fn set_date_time_format(mut self, date_time_format: DateTimeFormat) -> Self {
+        self.date_time_format = date_time_format;
+        self
+    }
+
+    fn format_time(&self, date_time: &DateTime) -> String {
+        let date_time = date_time.with_time_zone(self.date_time_format.time_zone);
+        match &self.date_time_format.pattern {
+            Some(pattern) => date_time.strftime(pattern),
+            None => format!("{} {}", date_time.weekday(), date_time.time()),
+        }
+    }
+}
+
+impl Display for HourlyInfo<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let units = self.units.symbol();
+        let temperature = |value| self.units.temperature.convert(value);
+        write!(
+            f,
+            "{city}, {country}:\n{time:<9}  {temperature:>7}  {feels_like:>7}  {precipitation:>4}  Wind",
+            city = self.location.city,
+            country = self.location.country,
+            time = "Time",
+            temperature = "Temp",
+            feels_like = "Feels",
+            precipitation = "Rain",
+        )?;
+        for entry in self.forecast.hours(self.hours) {
+            write!(
+                f,
+                "\n{time:<9}  {temperature:>7}  {feels_like:>7}  {precipitation:>4}  {wind} {emoji}",
+                time = self.format_time(&entry.date_time),
+                temperature = format!("{}{}", temperature(entry.temperature), units),
+                feels_like = format!("{}{}", temperature(entry.feels_like), units),
+                precipitation = format!("{}%", entry.precipitation_probability),
+                wind = entry.wind.convert(self.units).format_speed(self.units),
+                emoji = entry.icon
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HourlyInfo;
+    use crate::{
+        datetime::DateTimeFormat,
+        fixtures::{forecast_entry, location},
+        forecast::model::{Forecast, ForecastEntry},
+        time_zone::TimeZone,
+        units::Units,
+    };
+
+    fn entry(dt: u64, temperature: f32, precipitation: i32, icon: &str) -> ForecastEntry {
+        ForecastEntry {
+            feels_like: temperature + 0.5,
+            precipitation_probability: precipitation,
+            ..forecast_entry(dt, temperature, temperature, icon, "")
+        }
+    }
+
+    #[test]
+    fn hourly_info_format() {
+        let location = location();
+        let forecast = Forecast {
+            entries: vec![
+                entry(1631620800, 25.8, 0, "☁️"),
+                entry(1631631600, 21.3, 27, "🌧"),
+                entry(1631642400, 9.5, 100, "🌧"),
+            ],
+        };
+        let info = HourlyInfo::new(&location, &forecast, Units::metric());
+        let text = "Monteiro, Brazil:
+Time          Temp    Feels  Rain  Wind
+Tue 12:00    25.8C    26.3C    0%  4.72 meter/sec ☁\u{fe0f}
+Tue 15:00    21.3C    21.8C   27%  4.72 meter/sec 🌧
+Tue 18:00     9.5C      10C  100%  4.72 meter/sec 🌧";
+        assert_eq!(info.to_string(), text);
+
+        let info = HourlyInfo::new(&location, &forecast, Units::imperial()).set_hours(6);
+        let text = "Monteiro, Brazil:
+Time          Temp    Feels  Rain  Wind
+Tue 12:00   78.44F   79.34F    0%  10.56 miles/hour ☁\u{fe0f}
+Tue 15:00   70.34F   71.24F   27%  10.56 miles/hour 🌧";
+        assert_eq!(info.to_string(), text);
+    }
+
+    #[test]
+    fn hourly_info_time_zone() {
+        let location = location();
+        let mut entry = entry(1631664000, 19.4, 0, "🌧");
+        entry.date_time = entry.date_time.set_offset(-10800);
+        let forecast = Forecast {
+            entries: vec![entry],
+        };
+        let info = HourlyInfo::new(&location, &forecast, Units::metric());
+        let text = "Monteiro, Brazil:
+Time          Temp    Feels  Rain  Wind
+Tue 21:00    19.4C    19.9C    0%  4.72 meter/sec 🌧";
+        assert_eq!(info.to_string(), text);
+
+        let info = HourlyInfo::new(&location, &forecast, Units::metric()).set_date_time_format(
+            DateTimeFormat::new(TimeZone::Utc, Some("%H:%M".to_string())),
+        );
+        let text = "Monteiro, Brazil:
+Time          Temp    Feels  Rain  Wind
+00:00        19.4C    19.9C    0%  4.72 meter/sec 🌧";
+        assert_eq!(info.to_string(), text);
+    }
+}
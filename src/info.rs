@@ -1,6 +1,18 @@
-use std::fmt::{Display, Formatter, Result};
+use std::{
+    fmt::{Display, Formatter, Result},
+    time::Duration,
+};
 
-use crate::{location::model::Location, units::Units, weather::model::Weather};
+use crate::{
+    air_quality::model::AirQuality,
+    alerts::model::Alerts,
+    astronomy::Astronomy,
+    datetime::{DateTime, DateTimeFormat},
+    location::{model::Location, provider::LocationProvider},
+    template::Template,
+    units::Units,
+    weather::model::Weather,
+};
 
 #[derive(Clone, Debug)]
 pub struct Info<'a> {
@@ -8,6 +20,12 @@ pub struct Info<'a> {
     weather: &'a Weather,
     units: Units,
     verbose: bool,
+    template: Option<&'a Template>,
+    stale_age: Option<Duration>,
+    location_provider: Option<LocationProvider>,
+    alerts: Option<&'a Alerts>,
+    air_quality: Option<&'a AirQuality>,
+    date_time_format: DateTimeFormat,
 }
 
 impl<'a> Info<'a> {
@@ -17,6 +35,12 @@ impl<'a> Info<'a> {
             weather,
             units,
             verbose: false,
+            template: None,
+            stale_age: None,
+            location_provider: None,
+            alerts: None,
+            air_quality: None,
+            date_time_format: DateTimeFormat::default(),
         }
     }
 
@@ -24,21 +48,136 @@ impl<'a> Info<'a> {
         self.verbose = verbose;
         self
     }
+
+    pub fn set_template(mut self, template: Option<&'a Template>) -> Self {
+        self.template = template;
+        self
+    }
+
+    pub fn set_stale_age(mut self, stale_age: Option<Duration>) -> Self {
+        self.stale_age = stale_age;
+        self
+    }
+
+    pub fn set_location_provider(mut self, location_provider: Option<LocationProvider>) -> Self {
+        self.location_provider = location_provider;
+        self
+    }
+
+    pub fn set_alerts(mut self, alerts: Option<&'a Alerts>) -> Self {
+        self.alerts = alerts;
+        self
+    }
+
+    pub fn set_air_quality(mut self, air_quality: Option<&'a AirQuality>) -> Self {
+        self.air_quality = air_quality;
+        self
+    }
+
+    pub fn set_date_time_format(mut self, date_time_format: DateTimeFormat) -> Self {
+        self.date_time_format = date_time_format;
+        self
+    }
+
+    fn format_date_time(&self, date_time: &DateTime) -> String {
+        date_time.format(&self.date_time_format)
+    }
+
+    fn write_solar(&self, f: &mut Formatter<'_>) -> Result {
+        if let Some(solar_noon) = &self.weather.solar_noon {
+            write!(f, "\nSolar noon: {}", self.format_date_time(solar_noon))?;
+        }
+        if let Some(day_length) = self.weather.day_length {
+            write!(
+                f,
+                "\nDay length: {}",
+                humantime::format_duration(Duration::from_secs(day_length / 60 * 60))
+            )?;
+        }
+        match (self.weather.uv_index, self.weather.uv_risk()) {
+            (Some(uv_index), Some(risk)) => write!(f, "\nUV index: {} ({})", uv_index, risk),
+            _ => Ok(()),
+        }
+    }
+
+    fn write_air_quality(&self, f: &mut Formatter<'_>) -> Result {
+        match self.air_quality {
+            Some(air_quality) => write!(f, "\n{}", air_quality),
+            None => Ok(()),
+        }
+    }
+
+    fn write_alerts(&self, f: &mut Formatter<'_>) -> Result {
+        for alert in self.alerts.iter().flat_map(|alerts| &alerts.entries) {
+            write!(f, "\n{}", alert.format(&self.date_time_format))?;
+        }
+        Ok(())
+    }
+
+    fn write_location_provider(&self, f: &mut Formatter<'_>) -> Result {
+        match self.location_provider {
+            Some(provider) => write!(f, "\nLocation provider: {}", provider),
+            None => Ok(()),
+        }
+    }
+
+    fn write_stale(&self, f: &mut Formatter<'_>) -> Result {
+        match self.stale_age {
+            Some(age) => write!(
+                f,
+                " [stale: {} old, saved for {}]",
+                humantime::format_duration(Duration::from_secs(age.as_secs() / 60 * 60)),
+                self.location.name()
+            ),
+            None => Ok(()),
+        }
+    }
 }
 
 impl Display for Info<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if let Some(template) = self.template {
+            write!(
+                f,
+                "{}",
+                template.render(
+                    self.location,
+                    self.weather,
+                    self.units,
+                    &self.date_time_format
+                )
+            )?;
+            return self.write_stale(f);
+        }
         let units = self.units.symbol();
+        let weather = self.weather.convert(self.units);
         let info = write!(
             f,
-            "{city}, {country}: {temperature}{units} {emoji}",
-            city = self.location.city,
-            country = self.location.country,
-            temperature = self.weather.temperature,
+            "{name}: {temperature}{units} {emoji}",
+            name = self.location.name(),
+            temperature = weather.temperature,
             units = units,
-            emoji = self.weather.icon
-        );
+            emoji = weather.icon
+        )
+        .and(self.write_stale(f))
+        .and(self.write_alerts(f));
         if self.verbose {
+            let precipitation = weather
+                .precipitation
+                .map(|precipitation| {
+                    format!(
+                        "\nPrecipitation: {} {}",
+                        precipitation, self.units.precipitation
+                    )
+                })
+                .unwrap_or_default();
+            let codes: String = [
+                ("Country code", &self.location.country_code),
+                ("Postal code", &self.location.postal_code),
+            ]
+            .iter()
+            .filter_map(|(label, code)| code.as_ref().map(|code| format!("\n{}: {}", label, code)))
+            .collect();
             info.and(write!(
                 f,
                 "
@@ -47,39 +186,54 @@ Feels like: {feels_like}{units}
 Min: {min}{units}
 Max: {max}{units}
 Humidity: {humidity}%
-Pressure: {pressure} hPa
-Sea level: {sea_level} hPa
-Ground level: {ground_level} hPa
-Clouds: {clouds}%
+Pressure: {pressure} {pressure_unit}
+Sea level: {sea_level} {pressure_unit}
+Ground level: {ground_level} {pressure_unit}
+Clouds: {clouds}%{precipitation}
 Wind:
   Speed: {speed}
   Degrees: {degrees}
   Gust: {gust}
 Coordinates:
   Longitude: {longitude}
-  Latitude: {latitude}
+  Latitude: {latitude}{codes}
 Sunrise: {sunrise}
-Sunset: {sunset}
-Date/time: {date_time}",
-                description = self.weather.description,
-                feels_like = self.weather.feels_like,
+Sunset: {sunset}",
+                description = weather.description,
+                feels_like = weather.feels_like,
                 units = units,
-                min = self.weather.min_temperature,
-                max = self.weather.max_temperature,
-                humidity = self.weather.humidity,
-                pressure = self.weather.pressure,
-                sea_level = self.weather.sea_level.unwrap_or_default(),
-                ground_level = self.weather.ground_level.unwrap_or_default(),
-                clouds = self.weather.clouds,
-                speed = self.weather.wind.format_speed(self.units),
-                degrees = self.weather.wind.degrees,
-                gust = self.weather.wind.format_gust(self.units),
+                min = weather.min_temperature,
+                max = weather.max_temperature,
+                humidity = weather.humidity,
+                pressure = weather.pressure,
+                pressure_unit = self.units.pressure,
+                sea_level = weather.sea_level.unwrap_or_default(),
+                ground_level = weather.ground_level.unwrap_or_default(),
+                clouds = weather.clouds,
+                precipitation = precipitation,
+                speed = weather.wind.format_speed(self.units),
+                degrees = weather.wind.degrees,
+                gust = weather.wind.format_gust(self.units),
                 longitude = self.location.coordinates.longitude,
                 latitude = self.location.coordinates.latitude,
-                sunrise = self.weather.sunrise,
-                sunset = self.weather.sunset,
-                date_time = self.weather.date_time,
+                codes = codes,
+                sunrise = self.format_date_time(&weather.sunrise),
+                sunset = self.format_date_time(&weather.sunset),
+            ))
+            .and(self.write_solar(f))
+            .and(write!(
+                f,
+                "\n{}",
+                Astronomy::new(&self.location.coordinates, &weather.date_time)
+                    .format(&self.date_time_format)
+            ))
+            .and(write!(
+                f,
+                "\nDate/time: {}",
+                self.format_date_time(&weather.date_time)
             ))
+            .and(self.write_air_quality(f))
+            .and(self.write_location_provider(f))
         } else {
             info
         }
@@ -88,52 +242,59 @@ Date/time: {date_time}",
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::Info;
     use crate::{
-        datetime::DateTime,
-        location::model::{Coordinates, Location},
-        units::Units::{Celsius, Fahrenheit},
-        weather::model::{Weather, Wind},
+        air_quality::model::AirQuality,
+        alerts::model::{Alert, AlertSeverity, Alerts},
+        datetime::{DateTime, DateTimeFormat},
+        fixtures,
+        location::{model::Location, provider::LocationProvider},
+        solar::SolarDay,
+        template::Template,
+        time_zone::TimeZone,
+        units::Units,
+        weather::model::Weather,
     };
 
+    fn location() -> Location {
+        Location {
+            region: Some("Paraíba".to_string()),
+            country_code: Some("BR".to_string()),
+            postal_code: Some("58500-000".to_string()),
+            ..fixtures::location()
+        }
+    }
+
+    fn weather() -> Weather {
+        Weather {
+            ground_level: Some(949.0),
+            precipitation: Some(2.5),
+            ..fixtures::weather()
+        }
+    }
+
     #[test]
     fn info_format() {
-        let location = Location {
-            city: "Monteiro".to_string(),
-            country: "Brazil".to_string(),
-            coordinates: Coordinates::new(-7.9194, -37.175),
-        };
-        let weather = Weather {
-            temperature: 25.8,
-            icon: "☀️".to_string(),
-            description: "Scattered clouds".to_string(),
-            feels_like: 25.87,
-            min_temperature: 25.8,
-            max_temperature: 25.8,
-            pressure: 1017,
-            humidity: 55,
-            sea_level: Some(1017),
-            ground_level: Some(949),
-            wind: Wind {
-                speed: 4.72,
-                degrees: 115,
-                gust: 6.14,
-            },
-            clouds: 46,
-            date_time: DateTime::from_unix(1631620646),
-            sunrise: DateTime::from_unix(1631607769),
-            sunset: DateTime::from_unix(1631651152),
-        };
-        let units = Celsius;
-        let info = Info::new(&location, &weather, units);
-        assert_eq!(info.to_string(), "Monteiro, Brazil: 25.8C ☀\u{fe0f}");
-        let units = Fahrenheit;
-        let info = Info::new(&location, &weather, units);
-        assert_eq!(info.to_string(), "Monteiro, Brazil: 25.8F ☀\u{fe0f}");
-
-        let units = Celsius;
-        let info = Info::new(&location, &weather, units).set_verbose(true);
-        let text = "Monteiro, Brazil: 25.8C ☀\u{fe0f}
+        let (location, weather) = (location(), weather());
+        let info = Info::new(&location, &weather, Units::metric());
+        assert_eq!(
+            info.to_string(),
+            "Monteiro, Paraíba, Brazil: 25.8C ☀\u{fe0f}"
+        );
+        let info = Info::new(&location, &weather, Units::imperial());
+        assert_eq!(
+            info.to_string(),
+            "Monteiro, Paraíba, Brazil: 78.44F ☀\u{fe0f}"
+        );
+    }
+
+    #[test]
+    fn info_format_verbose() {
+        let (location, weather) = (location(), weather());
+        let info = Info::new(&location, &weather, Units::metric()).set_verbose(true);
+        let text = "Monteiro, Paraíba, Brazil: 25.8C ☀\u{fe0f}
 Weather: Scattered clouds
 Feels like: 25.87C
 Min: 25.8C
@@ -143,6 +304,7 @@ Pressure: 1017 hPa
 Sea level: 1017 hPa
 Ground level: 949 hPa
 Clouds: 46%
+Precipitation: 2.5 mm
 Wind:
   Speed: 4.72 meter/sec
   Degrees: 115
@@ -150,32 +312,229 @@ Wind:
 Coordinates:
   Longitude: -37.175
   Latitude: -7.9194
+Country code: BR
+Postal code: 58500-000
 Sunrise: 2021-09-14T08:22:49Z
 Sunset: 2021-09-14T20:25:52Z
+Moon: First quarter, 53% illuminated
+Moonrise: 2021-09-14T14:46:23Z
+Moonset: 2021-09-14T02:48:27Z
+Twilight:
+  Civil: 2021-09-14T08:01:57Z - 2021-09-14T20:46:47Z
+  Nautical: 2021-09-14T07:37:42Z - 2021-09-14T21:11:02Z
+  Astronomical: 2021-09-14T07:13:28Z - 2021-09-14T21:35:16Z
+Golden hour:
+  Morning: 2021-09-14T08:10:02Z - 2021-09-14T08:50:30Z
+  Evening: 2021-09-14T19:58:14Z - 2021-09-14T20:38:42Z
+Blue hour:
+  Morning: 2021-09-14T08:01:57Z - 2021-09-14T08:10:02Z
+  Evening: 2021-09-14T20:38:42Z - 2021-09-14T20:46:47Z
 Date/time: 2021-09-14T11:57:26Z";
         assert_eq!(info.to_string(), text);
-        let units = Fahrenheit;
-        let info = Info::new(&location, &weather, units).set_verbose(true);
-        let text = "Monteiro, Brazil: 25.8F ☀\u{fe0f}
+        let info = Info::new(&location, &weather, Units::imperial()).set_verbose(true);
+        let text = "Monteiro, Paraíba, Brazil: 78.44F ☀\u{fe0f}
 Weather: Scattered clouds
-Feels like: 25.87F
-Min: 25.8F
-Max: 25.8F
+Feels like: 78.57F
+Min: 78.44F
+Max: 78.44F
 Humidity: 55%
 Pressure: 1017 hPa
 Sea level: 1017 hPa
 Ground level: 949 hPa
 Clouds: 46%
+Precipitation: 2.5 mm
 Wind:
-  Speed: 4.72 miles/hour
+  Speed: 10.56 miles/hour
   Degrees: 115
-  Gust: 6.14 miles/hour
+  Gust: 13.73 miles/hour
 Coordinates:
   Longitude: -37.175
   Latitude: -7.9194
+Country code: BR
+Postal code: 58500-000
 Sunrise: 2021-09-14T08:22:49Z
 Sunset: 2021-09-14T20:25:52Z
+Moon: First quarter, 53% illuminated
+Moonrise: 2021-09-14T14:46:23Z
+Moonset: 2021-09-14T02:48:27Z
+Twilight:
+  Civil: 2021-09-14T08:01:57Z - 2021-09-14T20:46:47Z
+  Nautical: 2021-09-14T07:37:42Z - 2021-09-14T21:11:02Z
+  Astronomical: 2021-09-14T07:13:28Z - 2021-09-14T21:35:16Z
+Golden hour:
+  Morning: 2021-09-14T08:10:02Z - 2021-09-14T08:50:30Z
+  Evening: 2021-09-14T19:58:14Z - 2021-09-14T20:38:42Z
+Blue hour:
+  Morning: 2021-09-14T08:01:57Z - 2021-09-14T08:10:02Z
+  Evening: 2021-09-14T20:38:42Z - 2021-09-14T20:46:47Z
 Date/time: 2021-09-14T11:57:26Z";
         assert_eq!(info.to_string(), text);
     }
+
+    #[test]
+    fn info_location_provider() {
+        let (location, weather, units) = (location(), weather(), Units::imperial());
+        let text = Info::new(&location, &weather, units)
+            .set_verbose(true)
+            .to_string();
+        let info = Info::new(&location, &weather, units)
+            .set_verbose(true)
+            .set_location_provider(Some(LocationProvider::Ipapi));
+        assert_eq!(
+            info.to_string(),
+            format!("{}\nLocation provider: ipapi", text)
+        );
+        let info = Info::new(&location, &weather, units)
+            .set_location_provider(Some(LocationProvider::Ipapi));
+        assert_eq!(
+            info.to_string(),
+            "Monteiro, Paraíba, Brazil: 78.44F ☀\u{fe0f}"
+        );
+    }
+
+    #[test]
+    fn info_air_quality() {
+        let (location, weather, units) = (location(), weather(), Units::imperial());
+        let text = Info::new(&location, &weather, units)
+            .set_verbose(true)
+            .to_string();
+        let air_quality = AirQuality {
+            aqi: 2,
+            pm2_5: 0.5,
+            pm10: 0.54,
+            o3: 68.66,
+            no2: 0.77,
+            so2: 0.64,
+            co: 201.94,
+            date_time: DateTime::from_unix(1631620646),
+        };
+        let info = Info::new(&location, &weather, units)
+            .set_verbose(true)
+            .set_air_quality(Some(&air_quality))
+            .set_location_provider(Some(LocationProvider::Ipapi));
+        assert_eq!(
+            info.to_string(),
+            format!("{}\n{}\nLocation provider: ipapi", text, air_quality)
+        );
+        let info = Info::new(&location, &weather, units).set_air_quality(Some(&air_quality));
+        assert_eq!(
+            info.to_string(),
+            "Monteiro, Paraíba, Brazil: 78.44F ☀\u{fe0f}"
+        );
+    }
+
+    #[test]
+    fn info_alerts() {
+        let (location, weather, units) = (location(), weather(), Units::imperial());
+        let alerts = Alerts {
+            entries: vec![Alert {
+                event: "Flood Warning".to_string(),
+                severity: AlertSeverity::Severe,
+                start: DateTime::from_unix(1631620000),
+                end: DateTime::from_unix(1631660000),
+                sender: "INMET".to_string(),
+            }],
+        };
+        let info = Info::new(&location, &weather, units).set_alerts(Some(&alerts));
+        let header = "Monteiro, Paraíba, Brazil: 78.44F ☀\u{fe0f}";
+        let alert = "⚠️  FLOOD WARNING [severe]
+   From: 2021-09-14T11:46:40Z
+   Until: 2021-09-14T22:53:20Z
+   Sender: INMET";
+        assert_eq!(info.to_string(), format!("{}\n{}", header, alert));
+        let info = Info::new(&location, &weather, units)
+            .set_verbose(true)
+            .set_alerts(Some(&alerts));
+        assert!(info
+            .to_string()
+            .starts_with(&format!("{}\n{}\nWeather:", header, alert)));
+    }
+
+    #[test]
+    fn info_template() {
+        let (location, weather, units) = (location(), weather(), Units::imperial());
+        let template = Template::parse("{city}: {temperature:.0}{units} {humidity}%").unwrap();
+        let info = Info::new(&location, &weather, units)
+            .set_verbose(true)
+            .set_template(Some(&template));
+        assert_eq!(info.to_string(), "Monteiro: 78F 55%");
+    }
+
+    #[test]
+    fn info_stale() {
+        let (location, weather, units) = (location(), weather(), Units::imperial());
+        let info = Info::new(&location, &weather, units)
+            .set_stale_age(Some(Duration::from_secs(3 * 3600 + 25 * 60 + 12)));
+        assert_eq!(
+            info.to_string(),
+            "Monteiro, Paraíba, Brazil: 78.44F ☀\u{fe0f} [stale: 3h 25m old, saved for Monteiro, Paraíba, Brazil]"
+        );
+        let template = Template::parse("{city}: {temperature:.0}{units} {humidity}%").unwrap();
+        let info = Info::new(&location, &weather, units)
+            .set_template(Some(&template))
+            .set_stale_age(Some(Duration::from_secs(30)));
+        assert_eq!(
+            info.to_string(),
+            "Monteiro: 78F 55% [stale: 0s old, saved for Monteiro, Paraíba, Brazil]"
+        );
+    }
+
+    #[test]
+    fn info_solar_day() {
+        let location = location();
+        let mut weather = weather().set_solar_day(&SolarDay::new(
+            &location.coordinates,
+            &DateTime::from_unix(1631620646),
+        ));
+        let info = Info::new(&location, &weather, Units::metric()).set_verbose(true);
+        assert!(info.to_string().contains(
+            "Sunset: 2021-09-14T20:25:52Z
+Solar noon: 2021-09-14T14:24:"
+        ));
+        assert!(info.to_string().contains(
+            "
+Day length: 12h 3m
+Moon: First quarter, 53% illuminated"
+        ));
+        weather.uv_index = Some(7.35);
+        let info = Info::new(&location, &weather, Units::metric()).set_verbose(true);
+        assert!(info.to_string().contains(
+            "
+Day length: 12h 3m
+UV index: 7.35 (High)
+Moon: First quarter, 53% illuminated"
+        ));
+    }
+
+    #[test]
+    fn info_date_time_format() {
+        let location = location();
+        let mut weather = weather();
+        weather.date_time = weather.date_time.set_offset(-10800);
+        weather.sunrise = weather.sunrise.set_offset(-10800);
+        weather.sunset = weather.sunset.set_offset(-10800);
+        let solar_day = SolarDay::new(&location.coordinates, &weather.date_time);
+        let weather = weather.set_solar_day(&solar_day);
+        let info = Info::new(&location, &weather, Units::metric())
+            .set_verbose(true)
+            .set_date_time_format(DateTimeFormat::new(
+                TimeZone::Location,
+                Some("%H:%M".to_string()),
+            ));
+        let text = info.to_string();
+        assert!(text.contains("Sunrise: 05:22\nSunset: 17:25\nSolar noon: 11:24\n"));
+        assert!(text.contains("Moonrise: 11:46\n"));
+        assert!(text.contains("  Civil: 05:01 - 17:46\n"));
+        assert!(text.ends_with("Date/time: 08:57"));
+        let info = Info::new(&location, &weather, Units::metric())
+            .set_verbose(true)
+            .set_date_time_format(DateTimeFormat::new(TimeZone::Utc, None));
+        assert!(info
+            .to_string()
+            .ends_with("Date/time: 2021-09-14T11:57:26Z"));
+        let info = Info::new(&location, &weather, Units::metric()).set_verbose(true);
+        assert!(info
+            .to_string()
+            .ends_with("Date/time: 2021-09-14T08:57:26-03:00"));
+    }
 }
@@ -0,0 +1,305 @@
+use std::{
+    fmt::{Display, Error, Formatter, Result},
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::{
+    air_quality::model::AirQuality,
+    alerts::model::{Alert, Alerts},
+    datetime::DateTime,
+    location::model::Location,
+    units::Units,
+    weather::model::{Weather, Wind},
+};
+
+#[derive(Clone, Debug, Serialize)]
+struct JsonUnits {
+    system: &'static str,
+    temperature: String,
+    wind_speed: &'static str,
+    pressure: String,
+    precipitation: String,
+}
+
+impl From<Units> for JsonUnits {
+    fn from(units: Units) -> Self {
+        Self {
+            system: units.system(),
+            temperature: units.symbol(),
+            wind_speed: Wind::format_metric(units),
+            pressure: units.pressure.to_string(),
+            precipitation: units.precipitation.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct JsonAlert {
+    event: String,
+    severity: String,
+    start: DateTime,
+    end: DateTime,
+    sender: String,
+}
+
+impl From<&Alert> for JsonAlert {
+    fn from(alert: &Alert) -> Self {
+        Self {
+            event: alert.event.clone(),
+            severity: alert.severity.to_string(),
+            start: alert.start.clone(),
+            end: alert.end.clone(),
+            sender: alert.sender.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct JsonAirQuality {
+    aqi: i32,
+    category: &'static str,
+    pm2_5: f32,
+    pm10: f32,
+    o3: f32,
+    no2: f32,
+    so2: f32,
+    co: f32,
+    date_time: DateTime,
+}
+
+impl From<&AirQuality> for JsonAirQuality {
+    fn from(air_quality: &AirQuality) -> Self {
+        Self {
+            aqi: air_quality.aqi,
+            category: air_quality.category(),
+            pm2_5: air_quality.pm2_5,
+            pm10: air_quality.pm10,
+            o3: air_quality.o3,
+            no2: air_quality.no2,
+            so2: air_quality.so2,
+            co: air_quality.co,
+            date_time: air_quality.date_time.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct JsonInfo<'a> {
+    location: &'a Location,
+    weather: Weather,
+    units: JsonUnits,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alerts: Option<Vec<JsonAlert>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    air_quality: Option<JsonAirQuality>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stale_age: Option<u64>,
+}
+
+impl<'a> JsonInfo<'a> {
+    pub fn new(location: &'a Location, weather: &'a Weather, units: Units) -> Self {
+        Self {
+            location,
+            weather: weather.convert(units),
+            units: units.into(),
+            alerts: None,
+            air_quality: None,
+            stale_age: None,
+        }
+    }
+
+    pub fn set_alerts(mut self, alerts: Option<&Alerts>) -> Self {
+        self.alerts = alerts.map(|alerts| alerts.entries.iter().map(JsonAlert::from).collect());
+        self
+    }
+
+    pub fn set_air_quality(mut self, air_quality: Option<&AirQuality>) -> Self {
+        self.air_quality = air_quality.map(JsonAirQuality::from);
+        self
+    }
+
+    pub fn set_stale_age(mut self, stale_age: Option<Duration>) -> Self {
+        self.stale_age = stale_age.map(|age| age.as_secs());
+        self
+    }
+}
+
+impl Display for JsonInfo<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let json = serde_json::to_string_pretty(self).map_err(|_| Error)?;
+        write!(f, "{}", json)
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct JsonAirQualityInfo<'a> {
+    location: &'a Location,
+    air_quality: JsonAirQuality,
+}
+
+impl<'a> JsonAirQualityInfo<'a> {
+    pub fn new(location: &'a Location, air_quality: &'a AirQuality) -> Self {
+        Self {
+            location,
+            air_quality: air_quality.into(),
+        }
+    }
+}
+
+impl Display for JsonAirQualityInfo<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let json = serde_json::to_string_pretty(self).map_err(|_| Error)?;
+        write!(f, "{}", json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{JsonAirQualityInfo, JsonInfo};
+    use crate::{
+        air_quality::model::AirQuality,
+        alerts::model::{Alert, AlertSeverity, Alerts},
+        datetime::DateTime,
+        fixtures::{location, weather},
+        units::{PressureUnit, Units},
+    };
+
+    #[test]
+    fn json_info_format() {
+        let (location, weather) = (location(), weather());
+        let info = JsonInfo::new(&location, &weather, Units::metric());
+        let text = "{
+  \"location\": {
+    \"city\": \"Monteiro\",
+    \"region\": null,
+    \"country\": \"Brazil\",
+    \"country_code\": null,
+    \"postal_code\": null,
+    \"coordinates\": {
+      \"latitude\": -7.9194,
+      \"longitude\": -37.175
+    }
+  },
+  \"weather\": {
+    \"temperature\": 25.8,
+    \"icon\": \"☀\u{fe0f}\",
+    \"description\": \"Scattered clouds\",
+    \"feels_like\": 25.87,
+    \"min_temperature\": 25.8,
+    \"max_temperature\": 25.8,
+    \"pressure\": 1017.0,
+    \"humidity\": 55,
+    \"sea_level\": 1017.0,
+    \"ground_level\": null,
+    \"wind\": {
+      \"speed\": 4.72,
+      \"degrees\": 115,
+      \"gust\": 6.14
+    },
+    \"clouds\": 46,
+    \"date_time\": \"2021-09-14T11:57:26Z\",
+    \"sunrise\": \"2021-09-14T08:22:49Z\",
+    \"sunset\": \"2021-09-14T20:25:52Z\",
+    \"solar_noon\": null,
+    \"day_length\": null,
+    \"uv_index\": null,
+    \"precipitation\": null
+  },
+  \"units\": {
+    \"system\": \"metric\",
+    \"temperature\": \"C\",
+    \"wind_speed\": \"meter/sec\",
+    \"pressure\": \"hPa\",
+    \"precipitation\": \"mm\"
+  }
+}";
+        assert_eq!(info.to_string(), text);
+
+        let info = JsonInfo::new(&location, &weather, Units::imperial());
+        let json: serde_json::Value = serde_json::from_str(&info.to_string()).unwrap();
+        assert_eq!(json["units"]["system"], "imperial");
+        assert_eq!(json["units"]["temperature"], "F");
+        assert_eq!(json["units"]["wind_speed"], "miles/hour");
+        assert_eq!(json["units"]["pressure"], "hPa");
+        assert_eq!(json["weather"]["temperature"], 78.44);
+
+        let units = Units {
+            pressure: PressureUnit::Kilopascals,
+            ..Units::metric()
+        };
+        let info = JsonInfo::new(&location, &weather, units);
+        let json: serde_json::Value = serde_json::from_str(&info.to_string()).unwrap();
+        assert_eq!(json["units"]["system"], "custom");
+        assert_eq!(json["units"]["pressure"], "kPa");
+        assert!(json.get("stale_age").is_none());
+
+        let info = JsonInfo::new(&location, &weather, Units::metric())
+            .set_stale_age(Some(Duration::from_secs(3600)));
+        let json: serde_json::Value = serde_json::from_str(&info.to_string()).unwrap();
+        assert_eq!(json["stale_age"], 3600);
+        assert!(json.get("alerts").is_none());
+    }
+
+    #[test]
+    fn json_info_alerts() {
+        let (location, weather) = (location(), weather());
+        let alerts = Alerts {
+            entries: vec![Alert {
+                event: "Flood Warning".to_string(),
+                severity: AlertSeverity::Severe,
+                start: DateTime::from_unix(1631620000).set_offset(-10800),
+                end: DateTime::from_unix(1631660000).set_offset(-10800),
+                sender: "INMET".to_string(),
+            }],
+        };
+        let info = JsonInfo::new(&location, &weather, Units::metric()).set_alerts(Some(&alerts));
+        let json: serde_json::Value = serde_json::from_str(&info.to_string()).unwrap();
+        let alert = &json["alerts"][0];
+        assert_eq!(alert["event"], "Flood Warning");
+        assert_eq!(alert["severity"], "severe");
+        assert_eq!(alert["start"], "2021-09-14T08:46:40-03:00");
+        assert_eq!(alert["end"], "2021-09-14T19:53:20-03:00");
+        assert_eq!(alert["sender"], "INMET");
+
+        let info = JsonInfo::new(&location, &weather, Units::metric())
+            .set_alerts(Some(&Alerts::default()));
+        let json: serde_json::Value = serde_json::from_str(&info.to_string()).unwrap();
+        assert_eq!(json["alerts"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn json_info_air_quality() {
+        let (location, weather) = (location(), weather());
+        let air_quality = AirQuality {
+            aqi: 2,
+            pm2_5: 0.5,
+            pm10: 0.54,
+            o3: 68.66,
+            no2: 0.77,
+            so2: 0.64,
+            co: 201.94,
+            date_time: DateTime::from_unix(1631620646).set_offset(-10800),
+        };
+        let info = JsonInfo::new(&location, &weather, Units::metric());
+        let json: serde_json::Value = serde_json::from_str(&info.to_string()).unwrap();
+        assert!(json.get("air_quality").is_none());
+
+        let info = info.set_air_quality(Some(&air_quality));
+        let json: serde_json::Value = serde_json::from_str(&info.to_string()).unwrap();
+        let value = &json["air_quality"];
+        assert_eq!(value["aqi"], 2);
+        assert_eq!(value["category"], "Fair");
+        assert_eq!(value["o3"].as_f64().unwrap() as f32, 68.66);
+        assert_eq!(value["date_time"], "2021-09-14T08:57:26-03:00");
+
+        let info = JsonAirQualityInfo::new(&location, &air_quality);
+        let json: serde_json::Value = serde_json::from_str(&info.to_string()).unwrap();
+        assert_eq!(json["location"]["city"], "Monteiro");
+        assert_eq!(json["air_quality"]["category"], "Fair");
+        assert!(json.get("weather").is_none());
+    }
+}
@@ -1,11 +1,32 @@
+pub mod air_quality;
+pub mod air_quality_info;
+pub mod alerts;
+pub mod alerts_info;
+pub mod api_key;
 pub mod args;
+pub mod astronomy;
+pub mod cache;
 pub mod client;
+pub mod config;
 pub mod consts;
 pub mod datetime;
 pub mod emoji;
+#[cfg(test)]
+mod fixtures;
+pub mod forecast;
+pub mod forecast_info;
+pub mod hourly_info;
 pub mod info;
+pub mod json_info;
 pub mod location;
 pub mod options;
+pub mod output;
+pub mod paths;
+pub mod picker;
+pub mod snapshot;
+pub mod solar;
 pub mod spinner;
+pub mod template;
+pub mod time_zone;
 pub mod units;
 pub mod weather;
@@ -7,60 +7,60 @@ use serde::Deserialize;
 use thiserror::Error;
 
 use crate::{
+    cache::Cache,
     client::{ClientBuilder, ClientError},
     consts::TOKEN,
-    location::model::{Coordinates, Location},
+    location::{
+        model::{Coordinates, Location},
+        provider::LocationProvider,
+    },
 };
 
-pub const URL_LOCATIONS: [&str; 4] = [
-    "http://ip-api.com/json/",
-    "https://ipapi.co/json/",
-    "https://freegeoip.app/json/",
-    "https://ipwhois.app/json/",
-];
-
 pub const URL_QUERY_LOCATION: &str = "https://api.openweathermap.org/geo/1.0/direct";
+pub const URL_REVERSE_LOCATION: &str = "https://api.openweathermap.org/geo/1.0/reverse";
+pub const URL_ZIP_LOCATION: &str = "https://api.openweathermap.org/geo/1.0/zip";
 
 #[derive(Debug, Error)]
 pub enum LocationClientError {
     #[error(transparent)]
     Client(#[from] ClientError),
-    #[error("Wrong location provider")]
-    WrongLocationProvider,
+    #[error("No location provider to try")]
+    NoLocationProvider,
+    #[error("Implausible location returned by provider {0}")]
+    ImplausibleLocation(LocationProvider),
     #[error("Wrong query parameter")]
     WrongQueryParam,
-    #[error("Returning {0} cities, please choose one:\n{1}")]
-    MoreThanOne(usize, String),
+    #[error(
+        "Returning {} cities, please choose one:\n{}",
+        .0.len(),
+        format_candidates(.0)
+    )]
+    MoreThanOne(Vec<Location>),
+    #[error("Invalid choice {0}, expected 1 to {1}")]
+    InvalidPick(usize, usize),
+    #[error("Coordinates out of range: latitude {0} (-90 to 90), longitude {1} (-180 to 180)")]
+    CoordinatesOutOfRange(f32, f32),
 }
 
-#[derive(Debug)]
-pub struct LocationClient {
-    inner: ClientBuilder,
+impl LocationClientError {
+    pub fn is_offline(&self) -> bool {
+        matches!(self, Self::Client(error) if error.is_offline())
+    }
 }
 
-pub type LocationProvider = i8;
-
-#[derive(Clone, Debug, Deserialize)]
-struct LocationResponse {
-    pub city: String,
-    pub country: Option<String>,
-    pub country_name: Option<String>,
-    #[serde(alias = "lat")]
-    pub latitude: f32,
-    #[serde(alias = "lon")]
-    pub longitude: f32,
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LocationPick {
+    #[default]
+    Only,
+    First,
+    Index(usize),
 }
 
-impl From<LocationResponse> for Location {
-    fn from(response: LocationResponse) -> Self {
-        Self {
-            city: response.city,
-            country: response
-                .country_name
-                .unwrap_or(response.country.unwrap_or("N/D".to_string())),
-            coordinates: Coordinates::new(response.latitude, response.longitude),
-        }
-    }
+#[derive(Debug)]
+pub struct LocationClient {
+    inner: ClientBuilder,
+    api_key: String,
+    pick: LocationPick,
 }
 
 #[derive(Clone, Debug)]
@@ -94,7 +94,55 @@ impl Display for LocationQuery {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct LocationZipQuery {
+    pub zip_code: String,
+    pub country_code: Option<String>,
+}
+
+impl From<String> for LocationZipQuery {
+    fn from(query: String) -> Self {
+        let values: Vec<_> = query.split(',').collect();
+        Self {
+            zip_code: values[0].trim().to_string(),
+            country_code: values.get(1).map(|s| s.trim().to_string()),
+        }
+    }
+}
+
+impl Display for LocationZipQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", &self.zip_code)?;
+        if let Some(country_code) = &self.country_code {
+            write!(f, ",{}", country_code)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
+struct LocationZipResponse {
+    pub zip: String,
+    pub name: String,
+    pub country: Option<String>,
+    pub lat: f32,
+    pub lon: f32,
+}
+
+impl From<LocationZipResponse> for Location {
+    fn from(response: LocationZipResponse) -> Self {
+        Self {
+            city: response.name,
+            region: None,
+            country_code: response.country.clone(),
+            country: response.country.unwrap_or("N/D".to_string()),
+            postal_code: Some(response.zip),
+            coordinates: Coordinates::new(response.lat, response.lon),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
 struct LocationQueryResponse {
     pub name: String,
     pub state: Option<String>,
@@ -107,56 +155,63 @@ impl From<LocationQueryResponse> for Location {
     fn from(response: LocationQueryResponse) -> Self {
         Self {
             city: response.name,
+            region: response.state,
+            country_code: response.country.clone(),
             country: response.country.unwrap_or("N/D".to_string()),
+            postal_code: None,
             coordinates: Coordinates::new(response.lat, response.lon),
         }
     }
 }
 
-impl Default for LocationQueryResponse {
-    fn default() -> Self {
-        Self {
-            name: Default::default(),
-            state: Default::default(),
-            country: Default::default(),
-            lat: Default::default(),
-            lon: Default::default(),
+impl Location {
+    fn from_reverse(response: Option<LocationQueryResponse>, coordinates: &Coordinates) -> Self {
+        let mut location: Self = response.unwrap_or_default().into();
+        if location.city.is_empty() {
+            location.city = format!("{}, {}", coordinates.latitude, coordinates.longitude);
         }
+        location.coordinates = coordinates.clone();
+        location
     }
 }
 
-impl Display for LocationQueryResponse {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "City: {}\n", &self.name)?;
-        if let Some(state_code) = &self.state {
-            write!(f, "State code: {}\n", state_code)?;
-        }
-        if let Some(country_code) = &self.country {
-            write!(f, "Country code: {}\n", country_code)?;
+impl LocationPick {
+    pub fn apply(self, candidates: Vec<Location>) -> Result<Location, LocationClientError> {
+        match self {
+            Self::Only if candidates.len() > 1 => Err(LocationClientError::MoreThanOne(candidates)),
+            Self::Only | Self::First => Ok(candidates
+                .into_iter()
+                .next()
+                .unwrap_or_else(|| LocationQueryResponse::default().into())),
+            Self::Index(index) => {
+                let count = candidates.len();
+                index
+                    .checked_sub(1)
+                    .and_then(|position| candidates.into_iter().nth(position))
+                    .ok_or(LocationClientError::InvalidPick(index, count))
+            }
         }
-        write!(f, "Coordinates:\n")?;
-        write!(f, "  Latitude: {}\n", self.lat)?;
-        write!(f, "  Longitude: {}", self.lon)
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct LocationQueryResponses {
-    vec: Vec<LocationQueryResponse>,
-}
-
-impl LocationQueryResponses {
-    fn new(vec: Vec<LocationQueryResponse>) -> Self {
-        Self { vec }
+fn format_candidates(candidates: &[Location]) -> String {
+    let mut text = String::new();
+    for location in candidates {
+        text.push_str(&format!("\nCity: {}\n", location.city));
+        if let Some(region) = &location.region {
+            text.push_str(&format!("State code: {}\n", region));
+        }
+        text.push_str(&format!(
+            "Country code: {}\nCoordinates:\n  Latitude: {}\n  Longitude: {}\n",
+            location.country, location.coordinates.latitude, location.coordinates.longitude
+        ));
     }
+    text
 }
 
-impl Display for LocationQueryResponses {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for res in &self.vec {
-            write!(f, "\n{}\n", res.to_string())?;
-        }
-        Ok(())
+impl Default for LocationClient {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -164,6 +219,8 @@ impl LocationClient {
     pub fn new() -> Self {
         Self {
             inner: ClientBuilder::new(),
+            api_key: TOKEN.to_string(),
+            pick: LocationPick::default(),
         }
     }
 
@@ -175,20 +232,49 @@ impl LocationClient {
         self.with_inner(|inner| inner.set_timeout(timeout))
     }
 
+    pub fn set_proxy(self, proxy: Option<&str>) -> Self {
+        self.with_inner(|inner| inner.set_proxy(proxy))
+    }
+
+    pub fn set_cache(self, cache: Option<Cache>) -> Self {
+        self.with_inner(|inner| inner.set_cache(cache))
+    }
+
+    pub fn set_api_key(mut self, api_key: &str) -> Self {
+        self.api_key = api_key.to_string();
+        self
+    }
+
+    pub fn set_pick(mut self, pick: LocationPick) -> Self {
+        self.pick = pick;
+        self
+    }
+
     pub async fn get(
         self,
         provider: Option<LocationProvider>,
     ) -> Result<Location, LocationClientError> {
-        let provider_number = provider.unwrap_or_default();
-        if provider_number < 0 || provider_number as usize >= URL_LOCATIONS.len() {
-            return Err(LocationClientError::WrongLocationProvider);
-        }
-        let res: LocationResponse = self
-            .inner
-            .build()?
-            .get(URL_LOCATIONS[provider_number as usize])
+        let location = provider
+            .unwrap_or_default()
+            .fetch(&self.inner.build()?)
             .await?;
-        Ok(res.into())
+        Ok(location)
+    }
+
+    pub async fn get_with_fallback(
+        self,
+        providers: &[LocationProvider],
+    ) -> Result<(Location, LocationProvider), LocationClientError> {
+        let client = self.inner.build()?;
+        let mut last_error = LocationClientError::NoLocationProvider;
+        for &provider in providers {
+            match provider.fetch(&client).await {
+                Ok(location) if location.is_plausible() => return Ok((location, provider)),
+                Ok(_) => last_error = LocationClientError::ImplausibleLocation(provider),
+                Err(error) => last_error = error.into(),
+            }
+        }
+        Err(last_error)
     }
 
     pub async fn get_by_query(
@@ -202,21 +288,64 @@ impl LocationClient {
             "{url}?q={query}&limit=5&appid={appid}",
             url = URL_QUERY_LOCATION,
             query = query,
-            appid = TOKEN
+            appid = self.api_key
+        );
+        let res: Vec<LocationQueryResponse> = self
+            .inner
+            .build()?
+            .get(&url)
+            .await
+            .map_err(ClientError::for_api_key)?;
+        self.pick
+            .apply(res.into_iter().map(Location::from).collect())
+    }
+
+    pub async fn get_by_zip(
+        self,
+        query: &LocationZipQuery,
+    ) -> Result<Location, LocationClientError> {
+        if query.zip_code.is_empty() {
+            return Err(LocationClientError::WrongQueryParam);
+        };
+        let url = format!(
+            "{url}?zip={query}&appid={appid}",
+            url = URL_ZIP_LOCATION,
+            query = query,
+            appid = self.api_key
         );
-        let res = LocationQueryResponses::new(self.inner.build()?.get(&url).await?);
-        if res.vec.len() > 1 {
-            return Err(LocationClientError::MoreThanOne(
-                res.vec.len(),
-                res.to_string(),
+        let res: LocationZipResponse = self
+            .inner
+            .build()?
+            .get(&url)
+            .await
+            .map_err(ClientError::for_api_key)?;
+        Ok(res.into())
+    }
+
+    pub async fn get_by_coordinates(
+        self,
+        coordinates: &Coordinates,
+    ) -> Result<Location, LocationClientError> {
+        if !coordinates.is_valid() {
+            return Err(LocationClientError::CoordinatesOutOfRange(
+                coordinates.latitude,
+                coordinates.longitude,
             ));
         }
-        let loc = res
-            .vec
-            .first()
-            .unwrap_or(&LocationQueryResponse::default())
-            .clone();
-        Ok(loc.into())
+        let url = format!(
+            "{url}?lat={lat}&lon={lon}&limit=1&appid={appid}",
+            url = URL_REVERSE_LOCATION,
+            lat = coordinates.latitude,
+            lon = coordinates.longitude,
+            appid = self.api_key
+        );
+        let res: Vec<LocationQueryResponse> = self
+            .inner
+            .build()?
+            .get(&url)
+            .await
+            .map_err(ClientError::for_api_key)?;
+        Ok(Location::from_reverse(res.into_iter().next(), coordinates))
     }
 
     #[inline]
@@ -236,35 +365,30 @@ mod tests {
     use tokio::time::sleep;
 
     use super::{
-        Location, LocationClient, LocationQuery, LocationQueryResponse, LocationQueryResponses,
-        LocationResponse,
+        Coordinates, Location, LocationClient, LocationClientError, LocationPick, LocationProvider,
+        LocationQuery, LocationQueryResponse, LocationZipQuery, LocationZipResponse,
     };
 
+    #[tokio::test]
+    async fn location_client_get_with_fallback() {
+        sleep(Duration::from_secs(1)).await;
+        let (_, provider) = LocationClient::new()
+            .get_with_fallback(&[LocationProvider::IpApi, LocationProvider::Ipapi])
+            .await
+            .unwrap();
+        assert!(provider == LocationProvider::IpApi || provider == LocationProvider::Ipapi);
+        assert!(LocationClient::new().get_with_fallback(&[]).await.is_err());
+    }
+
     #[tokio::test]
     async fn location_client_get() {
         sleep(Duration::from_secs(1)).await;
         assert!(LocationClient::new().get(None).await.is_ok());
         sleep(Duration::from_secs(1)).await;
-        assert!(LocationClient::new().get(Some(-1)).await.is_err());
-        sleep(Duration::from_secs(1)).await;
-        assert!(LocationClient::new().get(Some(10)).await.is_err());
-    }
-
-    #[test]
-    fn location_from_response() {
-        let json = "{
-                \"city\": \"Monteiro\",
-                \"country_name\": \"Brazil\",
-                \"latitude\": -7.9194,
-                \"longitude\": -37.175
-            }";
-        let response = serde_json::from_str::<LocationResponse>(json);
-        assert!(response.is_ok());
-        let location: Location = response.unwrap().into();
-        assert_eq!(location.city, "Monteiro");
-        assert_eq!(location.country, "Brazil");
-        assert_eq!(location.coordinates.latitude, -7.9194);
-        assert_eq!(location.coordinates.longitude, -37.175);
+        assert!(LocationClient::new()
+            .get(Some(LocationProvider::Ipapi))
+            .await
+            .is_ok());
     }
 
     #[tokio::test]
@@ -278,6 +402,45 @@ mod tests {
 
         let query = LocationQuery::from("london".to_string());
         assert!(LocationClient::new().get_by_query(&query).await.is_err());
+        let location = LocationClient::new()
+            .set_pick(LocationPick::First)
+            .get_by_query(&query)
+            .await
+            .unwrap();
+        assert_eq!(location.city, "London");
+    }
+
+    #[tokio::test]
+    async fn location_client_get_by_zip() {
+        sleep(Duration::from_secs(1)).await;
+        let query = LocationZipQuery::from("90210,US".to_string());
+        let location = LocationClient::new().get_by_zip(&query).await.unwrap();
+        assert_eq!(location.city, "Beverly Hills");
+        assert_eq!(location.postal_code, Some("90210".to_string()));
+
+        let query = LocationZipQuery::from("".to_string());
+        assert!(matches!(
+            LocationClient::new().get_by_zip(&query).await,
+            Err(LocationClientError::WrongQueryParam)
+        ));
+    }
+
+    #[tokio::test]
+    async fn location_client_get_by_coordinates() {
+        sleep(Duration::from_secs(1)).await;
+        let coordinates = Coordinates::new(-7.9194, -37.175);
+        let location = LocationClient::new()
+            .get_by_coordinates(&coordinates)
+            .await
+            .unwrap();
+        assert_eq!(location.city, "Monteiro");
+        assert_eq!(location.coordinates, coordinates);
+
+        let coordinates = Coordinates::new(91.0, 0.0);
+        assert!(matches!(
+            LocationClient::new().get_by_coordinates(&coordinates).await,
+            Err(LocationClientError::CoordinatesOutOfRange(_, _))
+        ));
     }
 
     #[test]
@@ -293,11 +456,41 @@ mod tests {
         assert!(response.is_ok());
         let location: Location = response.unwrap().into();
         assert_eq!(location.city, "Monteiro");
+        assert_eq!(location.region, Some("PB".to_string()));
         assert_eq!(location.country, "BR");
+        assert_eq!(location.country_code, Some("BR".to_string()));
         assert_eq!(location.coordinates.latitude, -7.9194);
         assert_eq!(location.coordinates.longitude, -37.175);
     }
 
+    #[test]
+    fn location_from_zip_response() {
+        let json = "{
+                \"zip\": \"90210\",
+                \"name\": \"Beverly Hills\",
+                \"lat\": 34.0901,
+                \"lon\": -118.4065,
+                \"country\": \"US\"
+            }";
+        let response = serde_json::from_str::<LocationZipResponse>(json).unwrap();
+        let location: Location = response.into();
+        assert_eq!(location.city, "Beverly Hills");
+        assert_eq!(location.country, "US");
+        assert_eq!(location.country_code, Some("US".to_string()));
+        assert_eq!(location.postal_code, Some("90210".to_string()));
+        assert_eq!(location.coordinates, Coordinates::new(34.0901, -118.4065));
+    }
+
+    #[test]
+    fn location_zip_query_display() {
+        let query = LocationZipQuery::from("90210".to_string());
+        assert_eq!(query.to_string(), "90210");
+        let query = LocationZipQuery::from("E14, GB".to_string());
+        assert_eq!(query.zip_code, "E14");
+        assert_eq!(query.country_code, Some("GB".to_string()));
+        assert_eq!(query.to_string(), "E14,GB");
+    }
+
     #[test]
     fn location_query_display() {
         let query = LocationQuery::from("".to_string());
@@ -311,52 +504,7 @@ mod tests {
     }
 
     #[test]
-    fn location_query_response_display() {
-        let location = LocationQueryResponse {
-            name: "Monteiro".to_string(),
-            state: Some("PB".to_string()),
-            country: Some("BR".to_string()),
-            lat: 12.34,
-            lon: 56.78,
-        };
-        let text = "City: Monteiro
-State code: PB
-Country code: BR
-Coordinates:
-  Latitude: 12.34
-  Longitude: 56.78";
-        assert_eq!(location.to_string(), text);
-
-        let location = LocationQueryResponse {
-            name: "Monteiro".to_string(),
-            state: None,
-            country: Some("BR".to_string()),
-            lat: 12.34,
-            lon: 56.78,
-        };
-        let text = "City: Monteiro
-Country code: BR
-Coordinates:
-  Latitude: 12.34
-  Longitude: 56.78";
-        assert_eq!(location.to_string(), text);
-
-        let location = LocationQueryResponse {
-            name: "Monteiro".to_string(),
-            state: None,
-            country: None,
-            lat: 12.34,
-            lon: 56.78,
-        };
-        let text = "City: Monteiro
-Coordinates:
-  Latitude: 12.34
-  Longitude: 56.78";
-        assert_eq!(location.to_string(), text);
-    }
-
-    #[test]
-    fn location_query_responses_display() {
+    fn location_client_error_more_than_one() {
         let json = "[
             {
               \"name\": \"London\",
@@ -392,7 +540,8 @@ Coordinates:
               \"state\": \"CA\"
             }
           ]";
-        let text = "
+        let text = "Returning 5 cities, please choose one:
+
 City: London
 Country code: GB
 Coordinates:
@@ -426,7 +575,68 @@ Coordinates:
   Latitude: 36.4761
   Longitude: -119.4432
 ";
-        let res = LocationQueryResponses::new(serde_json::from_str(json).unwrap());
-        assert_eq!(res.to_string(), text);
+        let res: Vec<LocationQueryResponse> = serde_json::from_str(json).unwrap();
+        let candidates = res.into_iter().map(Location::from).collect();
+        let error = LocationClientError::MoreThanOne(candidates);
+        assert_eq!(error.to_string(), text);
+    }
+
+    #[test]
+    fn location_pick_apply() {
+        let json = "[
+            { \"name\": \"London\", \"lat\": 51.5085, \"lon\": -0.1257, \"country\": \"GB\" },
+            {
+              \"name\": \"London\",
+              \"lat\": 39.8865,
+              \"lon\": -83.4483,
+              \"country\": \"US\",
+              \"state\": \"OH\"
+            }
+          ]";
+        let res: Vec<LocationQueryResponse> = serde_json::from_str(json).unwrap();
+        let candidates: Vec<Location> = res.into_iter().map(Location::from).collect();
+        match LocationPick::Only.apply(candidates.clone()) {
+            Err(LocationClientError::MoreThanOne(locations)) => {
+                assert_eq!(locations.len(), 2);
+                assert_eq!(locations[1].region, Some("OH".to_string()));
+                assert_eq!(locations[1].country, "US");
+            }
+            _ => panic!("expected more than one location"),
+        }
+        let location = LocationPick::First.apply(candidates.clone()).unwrap();
+        assert_eq!(location.country, "GB");
+        let location = LocationPick::Index(2).apply(candidates.clone()).unwrap();
+        assert_eq!(location.country, "US");
+        assert!(matches!(
+            LocationPick::Index(3).apply(candidates.clone()),
+            Err(LocationClientError::InvalidPick(3, 2))
+        ));
+        assert!(matches!(
+            LocationPick::Index(0).apply(candidates),
+            Err(LocationClientError::InvalidPick(0, 2))
+        ));
+        assert_eq!(LocationPick::Only.apply(vec![]).unwrap().city, "");
+    }
+
+    #[test]
+    fn location_from_reverse() {
+        let coordinates = Coordinates::new(-7.91, -37.17);
+        let json = "{
+                \"name\": \"Monteiro\",
+                \"state\": \"Paraíba\",
+                \"country\": \"BR\",
+                \"lat\": -7.9194,
+                \"lon\": -37.175
+            }";
+        let response = serde_json::from_str(json).unwrap();
+        let location = Location::from_reverse(Some(response), &coordinates);
+        assert_eq!(location.city, "Monteiro");
+        assert_eq!(location.region, Some("Paraíba".to_string()));
+        assert_eq!(location.coordinates, coordinates);
+
+        let location = Location::from_reverse(None, &coordinates);
+        assert_eq!(location.city, "-7.91, -37.17");
+        assert_eq!(location.country, "N/D");
+        assert_eq!(location.coordinates, coordinates);
     }
 }
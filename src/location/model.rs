@@ -1,4 +1,9 @@
-#[derive(Clone, Debug)]
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Coordinates {
     pub latitude: f32,
     pub longitude: f32,
@@ -11,11 +16,141 @@ impl Coordinates {
             longitude,
         }
     }
+
+    pub fn is_valid(&self) -> bool {
+        (-90.0..=90.0).contains(&self.latitude) && (-180.0..=180.0).contains(&self.longitude)
+    }
+
+    // Nautical time zone, for providers that don't report the location's offset.
+    pub fn offset(&self) -> i32 {
+        (self.longitude / 15.0).round() as i32 * 3600
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid coordinates `{0}`, expected LAT,LON")]
+pub struct CoordinatesParseError(String);
+
+impl FromStr for Coordinates {
+    type Err = CoordinatesParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || CoordinatesParseError(value.to_string());
+        let (latitude, longitude) = value.split_once(',').ok_or_else(error)?;
+        Ok(Self::new(
+            latitude.trim().parse().map_err(|_| error())?,
+            longitude.trim().parse().map_err(|_| error())?,
+        ))
+    }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Location {
     pub city: String,
+    #[serde(default)]
+    pub region: Option<String>,
     pub country: String,
+    #[serde(default)]
+    pub country_code: Option<String>,
+    #[serde(default)]
+    pub postal_code: Option<String>,
     pub coordinates: Coordinates,
 }
+
+impl Location {
+    pub fn is_plausible(&self) -> bool {
+        !self.city.trim().is_empty()
+            && self.coordinates.is_valid()
+            && (self.coordinates.latitude != 0.0 || self.coordinates.longitude != 0.0)
+    }
+
+    pub fn name(&self) -> String {
+        let mut name = self.city.clone();
+        for part in self.region.iter().chain(Some(&self.country)) {
+            name.push_str(", ");
+            name.push_str(part);
+        }
+        name
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{} ({}, {})",
+            self.name(),
+            self.coordinates.latitude,
+            self.coordinates.longitude
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Coordinates, CoordinatesParseError, Location};
+    use crate::fixtures;
+
+    #[test]
+    fn coordinates_is_valid() {
+        assert!(Coordinates::new(-7.9194, -37.175).is_valid());
+        assert!(Coordinates::new(90.0, -180.0).is_valid());
+        assert!(!Coordinates::new(90.1, 0.0).is_valid());
+        assert!(!Coordinates::new(0.0, 180.5).is_valid());
+        assert!(!Coordinates::new(f32::NAN, 0.0).is_valid());
+    }
+
+    #[test]
+    fn coordinates_offset() {
+        assert_eq!(Coordinates::new(-7.9194, -37.175).offset(), -7200);
+        assert_eq!(Coordinates::new(40.7834, -73.9662).offset(), -18000);
+        assert_eq!(Coordinates::new(35.6895, 139.6917).offset(), 32400);
+        assert_eq!(Coordinates::new(51.4779, -0.0015).offset(), 0);
+    }
+
+    #[test]
+    fn coordinates_from_str() {
+        assert_eq!(
+            "-7.9194,-37.175".parse(),
+            Ok(Coordinates::new(-7.9194, -37.175))
+        );
+        assert_eq!("45.5, -122.68".parse(), Ok(Coordinates::new(45.5, -122.68)));
+        assert_eq!("95,200".parse(), Ok(Coordinates::new(95.0, 200.0)));
+        assert_eq!(
+            "-7.9194".parse::<Coordinates>(),
+            Err(CoordinatesParseError("-7.9194".to_string()))
+        );
+        assert_eq!(
+            "north,west".parse::<Coordinates>(),
+            Err(CoordinatesParseError("north,west".to_string()))
+        );
+    }
+
+    #[test]
+    fn location_is_plausible() {
+        let location = |city: &str, latitude, longitude| Location {
+            city: city.to_string(),
+            coordinates: Coordinates::new(latitude, longitude),
+            ..fixtures::location()
+        };
+        assert!(location("Monteiro", -7.9194, -37.175).is_plausible());
+        assert!(!location("", -7.9194, -37.175).is_plausible());
+        assert!(!location(" ", -7.9194, -37.175).is_plausible());
+        assert!(!location("Monteiro", 0.0, 0.0).is_plausible());
+        assert!(!location("Monteiro", -97.9, -37.175).is_plausible());
+    }
+
+    #[test]
+    fn location_summary() {
+        let mut location = Location {
+            city: "London".to_string(),
+            country: "GB".to_string(),
+            coordinates: Coordinates::new(51.5085, -0.1257),
+            ..fixtures::location()
+        };
+        assert_eq!(location.name(), "London, GB");
+        assert_eq!(location.summary(), "London, GB (51.5085, -0.1257)");
+        location.region = Some("OH".to_string());
+        location.country = "US".to_string();
+        location.coordinates = Coordinates::new(39.8865, -83.4483);
+        assert_eq!(location.name(), "London, OH, US");
+        assert_eq!(location.summary(), "London, OH, US (39.8865, -83.4483)");
+    }
+}
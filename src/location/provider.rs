@@ -0,0 +1,345 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    result,
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer};
+use thiserror::Error;
+
+use crate::{
+    client::{Client, ClientError},
+    location::model::{Coordinates, Location},
+};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LocationProvider {
+    #[default]
+    IpApi,
+    Ipapi,
+    FreeGeoIp,
+    IpWhois,
+}
+
+impl LocationProvider {
+    pub const ALL: [LocationProvider; 4] =
+        [Self::IpApi, Self::Ipapi, Self::FreeGeoIp, Self::IpWhois];
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Self::IpApi => "ip-api",
+            Self::Ipapi => "ipapi",
+            Self::FreeGeoIp => "freegeoip",
+            Self::IpWhois => "ipwhois",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn url(&self) -> &'static str {
+        match *self {
+            Self::IpApi => "http://ip-api.com/json/",
+            Self::Ipapi => "https://ipapi.co/json/",
+            Self::FreeGeoIp => "https://freegeoip.app/json/",
+            Self::IpWhois => "https://ipwhois.app/json/",
+        }
+    }
+
+    pub fn list() -> String {
+        let lines: Vec<String> = Self::ALL
+            .iter()
+            .map(|provider| {
+                format!(
+                    "{index}  {name:<10} {url}",
+                    index = provider.index(),
+                    name = provider.name(),
+                    url = provider.url()
+                )
+            })
+            .collect();
+        lines.join("\n")
+    }
+
+    pub(crate) async fn fetch(&self, client: &Client) -> result::Result<Location, ClientError> {
+        let url = self.url();
+        Ok(match *self {
+            Self::IpApi => client.get::<IpApiResponse>(url).await?.into(),
+            Self::Ipapi => client.get::<IpapiResponse>(url).await?.into(),
+            Self::FreeGeoIp => client.get::<FreeGeoIpResponse>(url).await?.into(),
+            Self::IpWhois => client.get::<IpWhoisResponse>(url).await?.into(),
+        })
+    }
+}
+
+impl Display for LocationProvider {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid location provider `{0}`, expected ip-api, ipapi, freegeoip or ipwhois")]
+pub struct LocationProviderParseError(String);
+
+impl FromStr for LocationProvider {
+    type Err = LocationProviderParseError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let value = value.trim();
+        Self::ALL
+            .iter()
+            .find(|provider| {
+                provider.name().eq_ignore_ascii_case(value) || provider.index().to_string() == value
+            })
+            .copied()
+            .ok_or_else(|| LocationProviderParseError(value.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for LocationProvider {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            Index(i64),
+            Name(String),
+        }
+        match Value::deserialize(deserializer)? {
+            Value::Index(index) => index.to_string(),
+            Value::Name(name) => name,
+        }
+        .parse()
+        .map_err(de::Error::custom)
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.trim().is_empty())
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IpApiResponse {
+    #[serde(default)]
+    city: String,
+    region_name: Option<String>,
+    country: Option<String>,
+    country_code: Option<String>,
+    zip: Option<String>,
+    lat: f32,
+    lon: f32,
+}
+
+impl From<IpApiResponse> for Location {
+    fn from(response: IpApiResponse) -> Self {
+        Self {
+            city: response.city,
+            region: non_empty(response.region_name),
+            country: response.country.unwrap_or_else(|| "N/D".to_string()),
+            country_code: non_empty(response.country_code),
+            postal_code: non_empty(response.zip),
+            coordinates: Coordinates::new(response.lat, response.lon),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct IpapiResponse {
+    #[serde(default)]
+    city: String,
+    region: Option<String>,
+    country_name: Option<String>,
+    country_code: Option<String>,
+    postal: Option<String>,
+    latitude: f32,
+    longitude: f32,
+}
+
+impl From<IpapiResponse> for Location {
+    fn from(response: IpapiResponse) -> Self {
+        Self {
+            city: response.city,
+            region: non_empty(response.region),
+            country: response.country_name.unwrap_or_else(|| "N/D".to_string()),
+            country_code: non_empty(response.country_code),
+            postal_code: non_empty(response.postal),
+            coordinates: Coordinates::new(response.latitude, response.longitude),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct FreeGeoIpResponse {
+    #[serde(default)]
+    city: String,
+    region_name: Option<String>,
+    country_name: Option<String>,
+    country_code: Option<String>,
+    zip_code: Option<String>,
+    latitude: f32,
+    longitude: f32,
+}
+
+impl From<FreeGeoIpResponse> for Location {
+    fn from(response: FreeGeoIpResponse) -> Self {
+        Self {
+            city: response.city,
+            region: non_empty(response.region_name),
+            country: response.country_name.unwrap_or_else(|| "N/D".to_string()),
+            country_code: non_empty(response.country_code),
+            postal_code: non_empty(response.zip_code),
+            coordinates: Coordinates::new(response.latitude, response.longitude),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct IpWhoisResponse {
+    #[serde(default)]
+    city: String,
+    region: Option<String>,
+    country: Option<String>,
+    country_code: Option<String>,
+    postal: Option<String>,
+    latitude: f32,
+    longitude: f32,
+}
+
+impl From<IpWhoisResponse> for Location {
+    fn from(response: IpWhoisResponse) -> Self {
+        Self {
+            city: response.city,
+            region: non_empty(response.region),
+            country: response.country.unwrap_or_else(|| "N/D".to_string()),
+            country_code: non_empty(response.country_code),
+            postal_code: non_empty(response.postal),
+            coordinates: Coordinates::new(response.latitude, response.longitude),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FreeGeoIpResponse, IpApiResponse, IpWhoisResponse, IpapiResponse, LocationProvider,
+        LocationProviderParseError,
+    };
+    use crate::location::model::Location;
+
+    #[test]
+    fn location_provider_from_str() {
+        assert_eq!("ip-api".parse(), Ok(LocationProvider::IpApi));
+        assert_eq!("IPAPI".parse(), Ok(LocationProvider::Ipapi));
+        assert_eq!("freegeoip".parse(), Ok(LocationProvider::FreeGeoIp));
+        assert_eq!("ipwhois".parse(), Ok(LocationProvider::IpWhois));
+        assert_eq!("0".parse(), Ok(LocationProvider::IpApi));
+        assert_eq!("3".parse(), Ok(LocationProvider::IpWhois));
+        assert_eq!(
+            "4".parse::<LocationProvider>(),
+            Err(LocationProviderParseError("4".to_string()))
+        );
+        assert_eq!(
+            "-1".parse::<LocationProvider>(),
+            Err(LocationProviderParseError("-1".to_string()))
+        );
+    }
+
+    #[test]
+    fn location_provider_deserialize() {
+        #[derive(serde::Deserialize)]
+        struct Config {
+            providers: Vec<LocationProvider>,
+        }
+        let config: Config = toml::from_str("providers = [1, \"ipwhois\", \"ip-api\"]").unwrap();
+        assert_eq!(
+            config.providers,
+            vec![
+                LocationProvider::Ipapi,
+                LocationProvider::IpWhois,
+                LocationProvider::IpApi
+            ]
+        );
+        assert!(toml::from_str::<Config>("providers = [7]").is_err());
+    }
+
+    #[test]
+    fn location_provider_list() {
+        let text = "0  ip-api     http://ip-api.com/json/
+1  ipapi      https://ipapi.co/json/
+2  freegeoip  https://freegeoip.app/json/
+3  ipwhois    https://ipwhois.app/json/";
+        assert_eq!(LocationProvider::list(), text);
+    }
+
+    fn assert_monteiro(location: Location, country: &str) {
+        assert_eq!(location.city, "Monteiro");
+        assert_eq!(location.country, country);
+        assert_eq!(location.coordinates.latitude, -7.9194);
+        assert_eq!(location.coordinates.longitude, -37.175);
+    }
+
+    #[test]
+    fn location_from_provider_responses() {
+        let json = "{
+                \"status\": \"success\",
+                \"country\": \"Brazil\",
+                \"countryCode\": \"BR\",
+                \"region\": \"PB\",
+                \"regionName\": \"Paraíba\",
+                \"city\": \"Monteiro\",
+                \"zip\": \"58500-000\",
+                \"lat\": -7.9194,
+                \"lon\": -37.175
+            }";
+        let response = serde_json::from_str::<IpApiResponse>(json).unwrap();
+        let location: Location = response.into();
+        assert_eq!(location.region, Some("Paraíba".to_string()));
+        assert_eq!(location.country_code, Some("BR".to_string()));
+        assert_eq!(location.postal_code, Some("58500-000".to_string()));
+        assert_monteiro(location, "Brazil");
+
+        let json = "{
+                \"city\": \"Monteiro\",
+                \"region\": \"Paraíba\",
+                \"country\": \"BR\",
+                \"country_name\": \"Brazil\",
+                \"country_code\": \"BR\",
+                \"postal\": null,
+                \"latitude\": -7.9194,
+                \"longitude\": -37.175
+            }";
+        let response = serde_json::from_str::<IpapiResponse>(json).unwrap();
+        let location: Location = response.into();
+        assert_eq!(location.region, Some("Paraíba".to_string()));
+        assert_eq!(location.country_code, Some("BR".to_string()));
+        assert_eq!(location.postal_code, None);
+        assert_monteiro(location, "Brazil");
+
+        let json = "{
+                \"country_code\": \"BR\",
+                \"region_name\": \"\",
+                \"city\": \"Monteiro\",
+                \"zip_code\": \"\",
+                \"latitude\": -7.9194,
+                \"longitude\": -37.175
+            }";
+        let response = serde_json::from_str::<FreeGeoIpResponse>(json).unwrap();
+        let location: Location = response.into();
+        assert_eq!(location.region, None);
+        assert_eq!(location.postal_code, None);
+        assert_monteiro(location, "N/D");
+
+        let json = "{
+                \"success\": true,
+                \"country\": \"Brazil\",
+                \"country_code\": \"BR\",
+                \"city\": \"Monteiro\",
+                \"latitude\": -7.9194,
+                \"longitude\": -37.175
+            }";
+        let response = serde_json::from_str::<IpWhoisResponse>(json).unwrap();
+        assert_monteiro(response.into(), "Brazil");
+    }
+}
@@ -1,40 +1,259 @@
-use std::{process, time::Duration};
+use std::{
+    io::{self, IsTerminal},
+    process,
+    time::Duration,
+};
 
 use wethr::{
+    air_quality::client::AirQualityClient,
+    air_quality_info::AirQualityInfo,
+    alerts::client::AlertsClient,
+    alerts_info::AlertsInfo,
+    api_key::ApiKey,
     args,
+    cache::{Cache, CACHE_TTL, LOCATION_CACHE_TTL},
     client::{CLIENT_CONNECT_TIMEOUT, CLIENT_TIMEOUT},
+    config::{Config, ConfigError},
+    consts::SNAPSHOT_FILE,
+    datetime::{DateTime, DateTimeFormat},
+    forecast::client::ForecastClient,
+    forecast_info::ForecastInfo,
+    hourly_info::HourlyInfo,
     info::Info,
-    location::client::{LocationClient, LocationQuery},
+    json_info::{JsonAirQualityInfo, JsonInfo},
+    location::{
+        client::{
+            LocationClient, LocationClientError, LocationPick, LocationQuery, LocationZipQuery,
+        },
+        model::Location,
+        provider::LocationProvider,
+    },
+    options::Command,
+    output::Output,
+    paths,
+    picker::Picker,
+    snapshot::Snapshot,
     spinner::{Spinner, SpinnerColor},
-    weather::client::WeatherClient,
+    template::Template,
+    units::Units,
+    weather::{client::WeatherClient, provider::WeatherProviderKind},
 };
 
+const ALERTS_EXIT_CODE: i32 = 2;
+
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> anyhow::Result<()> {
-    let opts = args::Args::parse_from_env()?;
-    if let Some(text) = opts.help.or(opts.version) {
+    let args = args::Args::parse_from_env()?;
+    if let Some(text) = args.help.as_ref().or(args.version.as_ref()) {
         println!("{}", text);
         process::exit(0);
     }
-    let spinner = Spinner::new().set_silent(opts.silent.is_some());
+    let config = Config::load(args)?;
+    let opts = config.options();
+    let template = opts.template.as_deref().map(Template::parse).transpose()?;
+    let output = opts.output.unwrap_or_default();
+    let date_time_format =
+        DateTimeFormat::new(opts.time_zone.unwrap_or_default(), opts.time_format.clone());
+    let spinner =
+        Spinner::new().set_silent(opts.silent.unwrap_or_default() || output == Output::Json);
     let connect_timeout = opts.connect_timeout.unwrap_or(CLIENT_CONNECT_TIMEOUT);
     let timeout = opts.timeout.unwrap_or(CLIENT_TIMEOUT);
-    let query = opts.query;
+    let proxy = opts.proxy.as_deref();
+    let cache_dir = paths::cache_dir().filter(|_| !opts.no_cache.unwrap_or_default());
+    let refresh = opts.refresh.unwrap_or_default();
+    let cache = |ttl: u64| {
+        cache_dir
+            .clone()
+            .map(|dir| Cache::new(dir, Duration::from_secs(ttl)).set_refresh(refresh))
+    };
+    let location_cache = cache(opts.location_cache_ttl.unwrap_or(LOCATION_CACHE_TTL));
+    let weather_cache = cache(opts.cache_ttl.unwrap_or(CACHE_TTL));
+    let api_key = ApiKey::resolve(opts.api_key);
+    if opts.command == Some(Command::ConfigShow) {
+        println!("{}", config.show(&api_key));
+        process::exit(0);
+    }
+    if opts.command == Some(Command::ListLocationProviders) {
+        println!("{}", LocationProvider::list());
+        process::exit(0);
+    }
+    let defaults = Units::from(opts.units.unwrap_or_default());
+    let units = Units {
+        temperature: opts.temperature_unit.unwrap_or(defaults.temperature),
+        wind_speed: opts.wind_unit.unwrap_or(defaults.wind_speed),
+        pressure: opts.pressure_unit.unwrap_or(defaults.pressure),
+        precipitation: opts.precipitation_unit.unwrap_or(defaults.precipitation),
+    };
+    let verbose = opts.full_info.unwrap_or_default();
+    let forecast_mode = opts.forecast.is_some() || opts.hourly.is_some();
+    let air = opts.air.unwrap_or_default();
+    let alerts_only = opts.alerts_only.unwrap_or_default();
+    let fallback = opts.fallback.unwrap_or_default() && !forecast_mode && !air && !alerts_only;
+    let weather_provider = opts.weather_provider.unwrap_or_default();
+    if opts.no_cache.unwrap_or_default()
+        && weather_provider.requires_cache()
+        && !forecast_mode
+        && !air
+        && !alerts_only
+    {
+        return Err(ConfigError::NoCache(weather_provider).into());
+    }
+    let snapshot_path = cache_dir.as_ref().map(|dir| dir.join(SNAPSHOT_FILE));
+    let load_snapshot = || snapshot_path.as_deref().and_then(Snapshot::load);
+    let query = opts.query.map(LocationQuery::from);
+    let coords = opts.coords;
+    let zip = opts.zip.map(LocationZipQuery::from);
     let location_provider = opts.location_provider;
+    let location_providers = opts.location_providers;
+    let pick = match (opts.pick, opts.first.unwrap_or_default()) {
+        (Some(index), _) => LocationPick::Index(index),
+        (None, true) => LocationPick::First,
+        (None, false) => LocationPick::Only,
+    };
+    let interactive = pick == LocationPick::Only
+        && !opts.silent.unwrap_or_default()
+        && output == Output::Text
+        && io::stdin().is_terminal()
+        && io::stdout().is_terminal();
     let location = spinner
         .set_color(SpinnerColor::Blue)
         .set_message("Detecting your location")
         .run(async {
             let location = LocationClient::new()
                 .set_connect_timeout(Duration::from_secs(connect_timeout))
-                .set_timeout(Duration::from_secs(timeout));
-            match query {
-                Some(query) => location.get_by_query(&LocationQuery::from(query)).await,
-                None => location.get(location_provider).await,
+                .set_timeout(Duration::from_secs(timeout))
+                .set_proxy(proxy)
+                .set_cache(location_cache)
+                .set_api_key(&api_key.value)
+                .set_pick(pick);
+            match (&coords, &zip, &query, location_providers) {
+                (Some(coords), _, _, _) => location
+                    .get_by_coordinates(coords)
+                    .await
+                    .map(|location| (location, None)),
+                (None, Some(zip), _, _) => location
+                    .get_by_zip(zip)
+                    .await
+                    .map(|location| (location, None)),
+                (None, None, Some(query), _) => location
+                    .get_by_query(query)
+                    .await
+                    .map(|location| (location, None)),
+                (None, None, None, Some(providers)) => location
+                    .get_with_fallback(&providers)
+                    .await
+                    .map(|(location, provider)| (location, Some(provider))),
+                (None, None, None, None) => location
+                    .get(location_provider)
+                    .await
+                    .map(|location| (location, Some(location_provider.unwrap_or_default()))),
             }
         })
-        .await?;
-    let units = opts.units.unwrap_or_default();
+        .await;
+    let (location, location_provider) = match location {
+        Ok(location) => location,
+        Err(error) if fallback && error.is_offline() => {
+            let mut snapshot = load_snapshot()
+                .filter(|snapshot| match (&coords, &zip, &query) {
+                    (Some(coords), _, _) => snapshot.matches_coordinates(coords),
+                    (None, Some(zip), _) => snapshot.matches_zip(zip),
+                    (None, None, Some(query)) => snapshot.matches_query(query),
+                    (None, None, None) => true,
+                })
+                .ok_or(error)?;
+            snapshot.units = units;
+            let age = snapshot.weather.date_time.age();
+            spinner.print_message(format_snapshot(
+                &snapshot,
+                output,
+                verbose,
+                template.as_ref(),
+                &date_time_format,
+                Some(age),
+                None,
+            ));
+            return Ok(());
+        }
+        Err(LocationClientError::MoreThanOne(candidates)) if interactive => {
+            let picker = Picker::new(
+                &format!("Found {} places with that name:", candidates.len()),
+                candidates.iter().map(Location::summary).collect(),
+            );
+            match spinner.suspend(|| picker.prompt(io::stdin().lock(), io::stdout()))? {
+                Some(index) => (LocationPick::Index(index).apply(candidates)?, None),
+                None => return Err(LocationClientError::MoreThanOne(candidates).into()),
+            }
+        }
+        Err(error) => return Err(error.into()),
+    };
+    let alerts_client = AlertsClient::new()
+        .set_connect_timeout(Duration::from_secs(connect_timeout))
+        .set_timeout(Duration::from_secs(timeout))
+        .set_proxy(proxy)
+        .set_cache(weather_cache.clone())
+        .set_api_key(&api_key.value)
+        .set_provider(weather_provider);
+    let air_quality_client = AirQualityClient::new()
+        .set_connect_timeout(Duration::from_secs(connect_timeout))
+        .set_timeout(Duration::from_secs(timeout))
+        .set_proxy(proxy)
+        .set_cache(weather_cache.clone())
+        .set_api_key(&api_key.value);
+    if air {
+        let air_quality = spinner
+            .set_color(SpinnerColor::Green)
+            .set_message("Loading air quality")
+            .run(air_quality_client.get(&location.coordinates))
+            .await?;
+        let text = match output {
+            Output::Text => AirQualityInfo::new(&location, &air_quality).to_string(),
+            Output::Json => JsonAirQualityInfo::new(&location, &air_quality).to_string(),
+        };
+        spinner.print_message(text);
+        return Ok(());
+    }
+    if alerts_only {
+        let alerts = spinner
+            .set_color(SpinnerColor::Red)
+            .set_message("Loading alerts")
+            .run(alerts_client.get(&location.coordinates))
+            .await?
+            .active(&DateTime::now());
+        let info = AlertsInfo::new(&location, &alerts).set_date_time_format(date_time_format);
+        spinner.print_message(info.to_string());
+        if !alerts.is_empty() {
+            process::exit(ALERTS_EXIT_CODE);
+        }
+        return Ok(());
+    }
+    if forecast_mode {
+        let forecast = spinner
+            .set_color(SpinnerColor::Yellow)
+            .set_message("Loading forecast")
+            .run(
+                ForecastClient::new()
+                    .set_connect_timeout(Duration::from_secs(connect_timeout))
+                    .set_timeout(Duration::from_secs(timeout))
+                    .set_proxy(proxy)
+                    .set_cache(weather_cache)
+                    .set_api_key(&api_key.value)
+                    .get(&location.coordinates),
+            )
+            .await?;
+        if let Some(days) = opts.forecast {
+            let info = ForecastInfo::new(&location, &forecast, units)
+                .set_days(days as usize)
+                .set_date_time_format(date_time_format.clone());
+            spinner.print_message(info.to_string());
+        }
+        if let Some(hours) = opts.hourly {
+            let info = HourlyInfo::new(&location, &forecast, units)
+                .set_hours(hours as u64)
+                .set_date_time_format(date_time_format.clone());
+            spinner.print_message(info.to_string());
+        }
+        return Ok(());
+    }
     let weather = spinner
         .set_color(SpinnerColor::Yellow)
         .set_message("Loading weather")
@@ -42,10 +261,106 @@ async fn main() -> anyhow::Result<()> {
             WeatherClient::new()
                 .set_connect_timeout(Duration::from_secs(connect_timeout))
                 .set_timeout(Duration::from_secs(timeout))
-                .get_with_units(&location.coordinates, units),
+                .set_proxy(proxy)
+                .set_cache(weather_cache)
+                .set_api_key(&api_key.value)
+                .set_provider(weather_provider)
+                .get(&location.coordinates),
         )
-        .await?;
-    let info = Info::new(&location, &weather, units).set_verbose(opts.full_info.is_some());
-    spinner.print_message(&info.to_string());
+        .await;
+    let weather = match weather {
+        Ok(weather) => weather,
+        Err(error) if fallback && error.is_offline() => {
+            let mut snapshot = load_snapshot()
+                .filter(|snapshot| snapshot.matches_coordinates(&location.coordinates))
+                .ok_or(error)?;
+            snapshot.units = units;
+            let age = snapshot.weather.date_time.age();
+            spinner.print_message(format_snapshot(
+                &snapshot,
+                output,
+                verbose,
+                template.as_ref(),
+                &date_time_format,
+                Some(age),
+                None,
+            ));
+            return Ok(());
+        }
+        Err(error) => return Err(error.into()),
+    };
+    // NWS alerts are free, One Call alerts need a separate subscription.
+    let alerts = if opts.alerts.unwrap_or_default() || weather_provider == WeatherProviderKind::Nws
+    {
+        let alerts = spinner
+            .set_color(SpinnerColor::Red)
+            .set_message("Loading alerts")
+            .run(alerts_client.get(&location.coordinates))
+            .await;
+        match alerts {
+            Ok(alerts) => Some(alerts.active(&DateTime::now())),
+            Err(error) => {
+                if verbose {
+                    spinner.print_warning(format!("Unable to load alerts: {}", error));
+                }
+                None
+            }
+        }
+    } else {
+        None
+    };
+    let air_quality = if verbose {
+        spinner
+            .set_color(SpinnerColor::Green)
+            .set_message("Loading air quality")
+            .run(air_quality_client.get(&location.coordinates))
+            .await
+            .ok()
+    } else {
+        None
+    };
+    let snapshot = Snapshot::new(location, weather, units)
+        .set_alerts(alerts)
+        .set_air_quality(air_quality);
+    if let Some(path) = &snapshot_path {
+        snapshot.save(path);
+    }
+    spinner.print_message(format_snapshot(
+        &snapshot,
+        output,
+        verbose,
+        template.as_ref(),
+        &date_time_format,
+        None,
+        location_provider,
+    ));
     Ok(())
 }
+
+fn format_snapshot(
+    snapshot: &Snapshot,
+    output: Output,
+    verbose: bool,
+    template: Option<&Template>,
+    date_time_format: &DateTimeFormat,
+    stale_age: Option<Duration>,
+    location_provider: Option<LocationProvider>,
+) -> String {
+    let (location, weather, units) = (&snapshot.location, &snapshot.weather, snapshot.units);
+    if output == Output::Json {
+        let info = JsonInfo::new(location, weather, units)
+            .set_alerts(snapshot.alerts.as_ref())
+            .set_air_quality(snapshot.air_quality.as_ref())
+            .set_stale_age(stale_age);
+        return info.to_string();
+    }
+    let info = Info::new(location, weather, units)
+        .set_verbose(verbose)
+        .set_template(template)
+        .set_stale_age(stale_age)
+        .set_location_provider(location_provider)
+        .set_alerts(snapshot.alerts.as_ref())
+        .set_air_quality(snapshot.air_quality.as_ref())
+        .set_date_time_format(date_time_format.clone());
+    info.to_string()
+}
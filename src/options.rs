@@ -1,14 +1,138 @@
-use crate::{location::client::LocationProvider, units::Units};
+use serde::Deserialize;
 
-#[derive(Clone, Debug)]
+use crate::{
+    location::{model::Coordinates, provider::LocationProvider},
+    output::Output,
+    time_zone::TimeZone,
+    units::{PrecipitationUnit, PressureUnit, SpeedUnit, TemperatureUnit, UnitSystem},
+    weather::provider::WeatherProviderKind,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Command {
+    ConfigShow,
+    ListLocationProviders,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct Options {
-    pub units: Option<Units>,
+    pub units: Option<UnitSystem>,
+    pub temperature_unit: Option<TemperatureUnit>,
+    pub wind_unit: Option<SpeedUnit>,
+    pub pressure_unit: Option<PressureUnit>,
+    pub precipitation_unit: Option<PrecipitationUnit>,
     pub connect_timeout: Option<u64>,
     pub timeout: Option<u64>,
+    pub proxy: Option<String>,
+    pub cache_ttl: Option<u64>,
+    pub location_cache_ttl: Option<u64>,
+    pub no_cache: Option<bool>,
+    #[serde(skip)]
+    pub refresh: Option<bool>,
+    pub fallback: Option<bool>,
+    #[serde(skip)]
+    pub api_key: Option<String>,
     pub query: Option<String>,
+    pub zip: Option<String>,
+    #[serde(skip)]
+    pub coords: Option<Coordinates>,
+    #[serde(skip)]
+    pub pick: Option<usize>,
+    pub first: Option<bool>,
     pub location_provider: Option<LocationProvider>,
+    pub location_providers: Option<Vec<LocationProvider>>,
+    pub weather_provider: Option<WeatherProviderKind>,
+    pub forecast: Option<u8>,
+    pub hourly: Option<u8>,
+    pub air: Option<bool>,
+    pub alerts: Option<bool>,
+    pub alerts_only: Option<bool>,
     pub full_info: Option<bool>,
+    pub template: Option<String>,
+    pub time_zone: Option<TimeZone>,
+    pub time_format: Option<String>,
+    pub output: Option<Output>,
     pub silent: Option<bool>,
+    #[serde(skip)]
+    pub command: Option<Command>,
+    #[serde(skip)]
     pub version: Option<String>,
+    #[serde(skip)]
     pub help: Option<String>,
 }
+
+impl Options {
+    pub fn merge(self, other: Options) -> Self {
+        Self {
+            units: self.units.or(other.units),
+            temperature_unit: self.temperature_unit.or(other.temperature_unit),
+            wind_unit: self.wind_unit.or(other.wind_unit),
+            pressure_unit: self.pressure_unit.or(other.pressure_unit),
+            precipitation_unit: self.precipitation_unit.or(other.precipitation_unit),
+            connect_timeout: self.connect_timeout.or(other.connect_timeout),
+            timeout: self.timeout.or(other.timeout),
+            proxy: self.proxy.or(other.proxy),
+            cache_ttl: self.cache_ttl.or(other.cache_ttl),
+            location_cache_ttl: self.location_cache_ttl.or(other.location_cache_ttl),
+            no_cache: self.no_cache.or(other.no_cache),
+            refresh: self.refresh.or(other.refresh),
+            fallback: self.fallback.or(other.fallback),
+            api_key: self.api_key.or(other.api_key),
+            query: self.query.or(other.query),
+            zip: self.zip.or(other.zip),
+            coords: self.coords.or(other.coords),
+            pick: self.pick.or(other.pick),
+            first: self.first.or(other.first),
+            location_provider: self.location_provider.or(other.location_provider),
+            location_providers: self.location_providers.or(other.location_providers),
+            weather_provider: self.weather_provider.or(other.weather_provider),
+            forecast: self.forecast.or(other.forecast),
+            hourly: self.hourly.or(other.hourly),
+            air: self.air.or(other.air),
+            alerts: self.alerts.or(other.alerts),
+            alerts_only: self.alerts_only.or(other.alerts_only),
+            full_info: self.full_info.or(other.full_info),
+            template: self.template.or(other.template),
+            time_zone: self.time_zone.or(other.time_zone),
+            time_format: self.time_format.or(other.time_format),
+            output: self.output.or(other.output),
+            silent: self.silent.or(other.silent),
+            command: self.command.or(other.command),
+            version: self.version.or(other.version),
+            help: self.help.or(other.help),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Options;
+    use crate::{
+        output::Output,
+        units::{SpeedUnit, UnitSystem},
+    };
+
+    #[test]
+    fn options_merge() {
+        let args = Options {
+            units: Some(UnitSystem::Imperial),
+            silent: Some(true),
+            ..Default::default()
+        };
+        let file = Options {
+            units: Some(UnitSystem::Metric),
+            wind_unit: Some(SpeedUnit::Knots),
+            timeout: Some(10),
+            output: Some(Output::Json),
+            ..Default::default()
+        };
+        let opts = args.merge(file);
+        assert_eq!(opts.units, Some(UnitSystem::Imperial));
+        assert_eq!(opts.wind_unit, Some(SpeedUnit::Knots));
+        assert_eq!(opts.timeout, Some(10));
+        assert_eq!(opts.output, Some(Output::Json));
+        assert_eq!(opts.silent, Some(true));
+        assert_eq!(opts.connect_timeout, None);
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    result,
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer};
+use thiserror::Error;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Output {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Display for Output {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match *self {
+            Self::Text => write!(fmt, "text"),
+            Self::Json => write!(fmt, "json"),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid output `{0}`, expected text or json")]
+pub struct OutputParseError(String);
+
+impl FromStr for Output {
+    type Err = OutputParseError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputParseError(value.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Output {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Output, OutputParseError};
+
+    #[test]
+    fn output_to_string() {
+        assert_eq!(Output::Text.to_string(), "text");
+        assert_eq!(Output::Json.to_string(), "json");
+    }
+
+    #[test]
+    fn output_from_str() {
+        assert_eq!("text".parse(), Ok(Output::Text));
+        assert_eq!("JSON".parse(), Ok(Output::Json));
+        assert_eq!(
+            "xml".parse::<Output>(),
+            Err(OutputParseError("xml".to_string()))
+        );
+    }
+}
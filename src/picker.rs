@@ -0,0 +1,73 @@
+use std::io::{self, BufRead, Write};
+
+#[derive(Clone, Debug)]
+pub struct Picker {
+    title: String,
+    choices: Vec<String>,
+}
+
+impl Picker {
+    pub fn new(title: &str, choices: Vec<String>) -> Self {
+        Self {
+            title: title.to_string(),
+            choices,
+        }
+    }
+
+    pub fn prompt<R, W>(&self, mut reader: R, mut writer: W) -> io::Result<Option<usize>>
+    where
+        R: BufRead,
+        W: Write,
+    {
+        writeln!(writer, "{}", self.title)?;
+        for (index, choice) in self.choices.iter().enumerate() {
+            writeln!(writer, "  {}) {}", index + 1, choice)?;
+        }
+        loop {
+            write!(writer, "Choose one [1-{}]: ", self.choices.len())?;
+            writer.flush()?;
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            let line = line.trim();
+            if line.is_empty() {
+                return Ok(None);
+            }
+            match line.parse::<usize>() {
+                Ok(index) if (1..=self.choices.len()).contains(&index) => return Ok(Some(index)),
+                _ => writeln!(writer, "Invalid choice `{}`", line)?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::Picker;
+
+    #[test]
+    fn picker_prompt() {
+        let picker = Picker::new(
+            "Several places match:",
+            vec!["London, GB".to_string(), "London, OH, US".to_string()],
+        );
+        let mut output = Vec::new();
+        let choice = picker.prompt(Cursor::new("3\nfoo\n2\n"), &mut output);
+        assert_eq!(choice.unwrap(), Some(2));
+        let text = "Several places match:
+  1) London, GB
+  2) London, OH, US
+Choose one [1-2]: Invalid choice `3`
+Choose one [1-2]: Invalid choice `foo`
+Choose one [1-2]: ";
+        assert_eq!(String::from_utf8(output).unwrap(), text);
+
+        let choice = picker.prompt(Cursor::new("\n"), Vec::new());
+        assert_eq!(choice.unwrap(), None);
+        let choice = picker.prompt(Cursor::new(""), Vec::new());
+        assert_eq!(choice.unwrap(), None);
+    }
+}
@@ -0,0 +1,155 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    air_quality::model::AirQuality,
+    alerts::model::Alerts,
+    location::{
+        client::{LocationQuery, LocationZipQuery},
+        model::{Coordinates, Location},
+    },
+    units::Units,
+    weather::model::Weather,
+};
+
+// Roughly 10 km, so a slightly different geocoding result still matches.
+const SNAPSHOT_DISTANCE: f32 = 0.1;
+
+fn same_text(value: Option<&String>, other: Option<&String>) -> bool {
+    match (value, other) {
+        (Some(value), Some(other)) => value.trim().eq_ignore_ascii_case(other.trim()),
+        _ => true,
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Snapshot {
+    pub location: Location,
+    pub weather: Weather,
+    pub units: Units,
+    #[serde(skip)]
+    pub alerts: Option<Alerts>,
+    #[serde(skip)]
+    pub air_quality: Option<AirQuality>,
+}
+
+impl Snapshot {
+    pub fn new(location: Location, weather: Weather, units: Units) -> Self {
+        Self {
+            location,
+            weather,
+            units,
+            alerts: None,
+            air_quality: None,
+        }
+    }
+
+    pub fn set_alerts(mut self, alerts: Option<Alerts>) -> Self {
+        self.alerts = alerts;
+        self
+    }
+
+    pub fn set_air_quality(mut self, air_quality: Option<AirQuality>) -> Self {
+        self.air_quality = air_quality;
+        self
+    }
+
+    pub fn matches_coordinates(&self, coordinates: &Coordinates) -> bool {
+        let location = &self.location.coordinates;
+        (location.latitude - coordinates.latitude).abs() <= SNAPSHOT_DISTANCE
+            && (location.longitude - coordinates.longitude).abs() <= SNAPSHOT_DISTANCE
+    }
+
+    pub fn matches_query(&self, query: &LocationQuery) -> bool {
+        let location = &self.location;
+        same_text(Some(&location.city), Some(&query.city_name))
+            && same_text(location.country_code.as_ref(), query.country_code.as_ref())
+    }
+
+    pub fn matches_zip(&self, query: &LocationZipQuery) -> bool {
+        let location = &self.location;
+        location.postal_code.is_some()
+            && same_text(location.postal_code.as_ref(), Some(&query.zip_code))
+            && same_text(location.country_code.as_ref(), query.country_code.as_ref())
+    }
+
+    pub fn load(path: &Path) -> Option<Self> {
+        let text = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&text).ok()
+    }
+
+    pub fn save(&self, path: &Path) {
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        if let Ok(text) = serde_json::to_string(self) {
+            let _ = fs::write(path, text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::Snapshot;
+    use crate::{
+        fixtures,
+        location::{
+            client::{LocationQuery, LocationZipQuery},
+            model::Coordinates,
+        },
+        units::Units,
+        weather::model::Weather,
+    };
+
+    fn snapshot() -> Snapshot {
+        let weather = Weather {
+            precipitation: Some(0.35),
+            ..fixtures::weather()
+        };
+        Snapshot::new(fixtures::location(), weather, Units::imperial())
+    }
+
+    #[test]
+    fn snapshot_save_load() {
+        let dir = env::temp_dir().join(format!("wethr-snapshot-{}", process::id()));
+        let path = dir.join("snapshot.json");
+        assert!(Snapshot::load(&path).is_none());
+        snapshot().save(&path);
+        let snapshot = Snapshot::load(&path).unwrap();
+        assert_eq!(snapshot.location.city, "Monteiro");
+        assert_eq!(snapshot.location.coordinates.latitude, -7.9194);
+        assert_eq!(snapshot.weather.temperature, 25.8);
+        assert_eq!(snapshot.weather.ground_level, None);
+        assert_eq!(snapshot.weather.wind.gust, 6.14);
+        assert_eq!(snapshot.weather.date_time.unix(), 1631620646);
+        assert_eq!(snapshot.units, Units::imperial());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn snapshot_matches() {
+        let mut snapshot = snapshot();
+        assert!(snapshot.matches_coordinates(&Coordinates::new(-7.9194, -37.175)));
+        assert!(snapshot.matches_coordinates(&Coordinates::new(-7.89, -37.12)));
+        assert!(!snapshot.matches_coordinates(&Coordinates::new(-7.9194, -36.5)));
+
+        let query = |text: &str| LocationQuery::from(text.to_string());
+        assert!(snapshot.matches_query(&query("monteiro")));
+        assert!(snapshot.matches_query(&query("Monteiro,PB,BR")));
+        assert!(!snapshot.matches_query(&query("London")));
+        snapshot.location.country_code = Some("BR".to_string());
+        assert!(snapshot.matches_query(&query("Monteiro,,br")));
+        assert!(!snapshot.matches_query(&query("Monteiro,,PT")));
+
+        let zip = |text: &str| LocationZipQuery::from(text.to_string());
+        assert!(!snapshot.matches_zip(&zip("58500-000")));
+        snapshot.location.postal_code = Some("58500-000".to_string());
+        assert!(snapshot.matches_zip(&zip("58500-000")));
+        assert!(snapshot.matches_zip(&zip("58500-000, BR")));
+        assert!(!snapshot.matches_zip(&zip("58500-000,US")));
+        assert!(!snapshot.matches_zip(&zip("90210")));
+    }
+}
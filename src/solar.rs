@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use crate::{datetime::DateTime, location::model::Coordinates};
+
+const J1970: f64 = 2440587.5;
+
+pub(crate) const J2000: f64 = 2451545.0;
+
+const DAYS_1970_TO_2000: f64 = 10957.0;
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+const SUNRISE_ALTITUDE: f64 = -0.833;
+
+pub(crate) const EARTH_OBLIQUITY: f64 = 23.4397;
+
+pub(crate) fn to_julian(date_time: &DateTime) -> f64 {
+    date_time.unix() as f64 / SECONDS_PER_DAY + J1970
+}
+
+pub(crate) fn from_julian(julian: f64) -> DateTime {
+    DateTime::from_unix(((julian - J1970) * SECONDS_PER_DAY).round().max(0.0) as u64)
+}
+
+#[derive(Clone, Debug)]
+pub struct SolarDay {
+    transit: f64,
+    declination: f64,
+    latitude: f64,
+    offset: i32,
+}
+
+impl SolarDay {
+    pub fn new(coordinates: &Coordinates, date: &DateTime) -> Self {
+        let days = date.local_days() as f64 - DAYS_1970_TO_2000;
+        let mean_noon = days - coordinates.longitude as f64 / 360.0;
+        let anomaly = (357.5291 + 0.98560028 * mean_noon)
+            .rem_euclid(360.0)
+            .to_radians();
+        let center =
+            1.9148 * anomaly.sin() + 0.02 * (2.0 * anomaly).sin() + 0.0003 * (3.0 * anomaly).sin();
+        let longitude = (anomaly.to_degrees() + center + 180.0 + 102.9372)
+            .rem_euclid(360.0)
+            .to_radians();
+        let transit = J2000 + mean_noon + 0.0053 * anomaly.sin() - 0.0069 * (2.0 * longitude).sin();
+        let declination = (longitude.sin() * EARTH_OBLIQUITY.to_radians().sin()).asin();
+        Self {
+            transit,
+            declination,
+            latitude: (coordinates.latitude as f64).to_radians(),
+            offset: date.offset(),
+        }
+    }
+
+    pub fn noon(&self) -> DateTime {
+        from_julian(self.transit).set_offset(self.offset)
+    }
+
+    fn cos_hour_angle(&self, altitude: f64) -> f64 {
+        (altitude.to_radians().sin() - self.latitude.sin() * self.declination.sin())
+            / (self.latitude.cos() * self.declination.cos())
+    }
+
+    fn hour_angle(&self, altitude: f64) -> Option<f64> {
+        let cos = self.cos_hour_angle(altitude);
+        if (-1.0..=1.0).contains(&cos) {
+            Some(cos.acos().to_degrees())
+        } else {
+            None
+        }
+    }
+
+    pub fn rise_set(&self, altitude: f64) -> Option<(DateTime, DateTime)> {
+        self.hour_angle(altitude).map(|angle| {
+            (
+                from_julian(self.transit - angle / 360.0).set_offset(self.offset),
+                from_julian(self.transit + angle / 360.0).set_offset(self.offset),
+            )
+        })
+    }
+
+    pub fn sunrise_sunset(&self) -> Option<(DateTime, DateTime)> {
+        self.rise_set(SUNRISE_ALTITUDE)
+    }
+
+    pub fn day_length(&self) -> Duration {
+        let angle = match self.hour_angle(SUNRISE_ALTITUDE) {
+            Some(angle) => angle,
+            None if self.cos_hour_angle(SUNRISE_ALTITUDE) < -1.0 => 180.0,
+            None => 0.0,
+        };
+        Duration::from_secs((angle / 180.0 * SECONDS_PER_DAY).round() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SolarDay;
+    use crate::{datetime::DateTime, location::model::Coordinates};
+
+    fn assert_near(date_time: &DateTime, unix: u64) {
+        let diff = (date_time.unix() as i64 - unix as i64).abs();
+        assert!(diff <= 120, "{} is {}s away from {}", date_time, diff, unix);
+    }
+
+    #[test]
+    fn solar_day_sunrise_sunset() {
+        let coordinates = Coordinates::new(-7.9194, -37.175);
+        let solar = SolarDay::new(&coordinates, &DateTime::from_unix(1631620646));
+        let (sunrise, sunset) = solar.sunrise_sunset().unwrap();
+        assert_near(&sunrise, 1631607769);
+        assert_near(&sunset, 1631651152);
+        assert_near(&solar.noon(), (1631607769 + 1631651152) / 2);
+        let day_length = solar.day_length().as_secs() as i64;
+        assert!((day_length - (1631651152 - 1631607769)).abs() <= 120);
+    }
+
+    #[test]
+    fn solar_day_polar() {
+        let coordinates = Coordinates::new(78.2232, 15.6267);
+        let solar = SolarDay::new(&coordinates, &DateTime::from_unix(1624233600));
+        assert!(solar.sunrise_sunset().is_none());
+        assert_eq!(solar.day_length().as_secs(), 86400);
+        let solar = SolarDay::new(&coordinates, &DateTime::from_unix(1640044800));
+        assert!(solar.sunrise_sunset().is_none());
+        assert_eq!(solar.day_length().as_secs(), 0);
+    }
+
+    #[test]
+    fn solar_day_offset() {
+        let coordinates = Coordinates::new(-7.9194, -37.175);
+        let date_time = DateTime::from_unix(1631620646).set_offset(-10800);
+        let solar = SolarDay::new(&coordinates, &date_time);
+        let (sunrise, sunset) = solar.sunrise_sunset().unwrap();
+        assert_eq!(sunrise.offset(), -10800);
+        assert_eq!(sunrise.time(), "05:22");
+        assert_eq!(sunset.time(), "17:25");
+        assert_eq!(solar.noon().offset(), -10800);
+
+        let date_time = DateTime::from_unix(1631671200).set_offset(-10800);
+        let (sunrise, _) = SolarDay::new(&coordinates, &date_time)
+            .sunrise_sunset()
+            .unwrap();
+        assert_eq!(sunrise.date(), "2021-09-14");
+        assert_eq!(sunrise.time(), "05:22");
+    }
+}
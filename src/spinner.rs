@@ -43,6 +43,12 @@ pub struct Spinner {
     silent: bool,
 }
 
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Spinner {
     pub fn new() -> Self {
         Spinner {
@@ -90,6 +96,27 @@ impl Spinner {
         self
     }
 
+    pub fn print_warning<T: Display>(&self, message: T) -> &Self {
+        self.suspend(|| eprintln!("Warning: {}", message));
+        self
+    }
+
+    pub fn suspend<F, T>(&self, func: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        if self.silent {
+            return func();
+        }
+        self.progress_bar
+            .set_draw_target(ProgressDrawTarget::hidden());
+        print!("\r\x1b[2K");
+        let result = func();
+        self.progress_bar
+            .set_draw_target(ProgressDrawTarget::stdout());
+        result
+    }
+
     pub async fn run<F, T>(&self, finish_fn: F) -> T
     where
         F: Future<Output = T>,
@@ -0,0 +1,475 @@
+use std::result;
+
+use thiserror::Error;
+
+use crate::{
+    datetime::DateTimeFormat, location::model::Location, units::Units, weather::model::Weather,
+};
+
+pub const TEMPLATE_FIELDS: [&str; 29] = [
+    "city",
+    "country",
+    "region",
+    "country_code",
+    "postal_code",
+    "coordinates.latitude",
+    "coordinates.longitude",
+    "temperature",
+    "units",
+    "icon",
+    "description",
+    "feels_like",
+    "min_temperature",
+    "max_temperature",
+    "pressure",
+    "humidity",
+    "sea_level",
+    "ground_level",
+    "wind.speed",
+    "wind.degrees",
+    "wind.gust",
+    "clouds",
+    "precipitation",
+    "date_time",
+    "sunrise",
+    "sunset",
+    "solar_noon",
+    "day_length",
+    "uv_index",
+];
+
+#[derive(Debug, Error, PartialEq)]
+pub enum TemplateError {
+    #[error("Unknown template placeholder `{{{0}}}`, available: {fields}", fields = TEMPLATE_FIELDS.join(", "))]
+    UnknownPlaceholder(String),
+    #[error("Invalid format spec `{1}` for template placeholder `{{{0}}}`")]
+    InvalidFormatSpec(String, String),
+    #[error("Unclosed template placeholder at position {0}")]
+    UnclosedPlaceholder(usize),
+    #[error("Unmatched `}}` in template at position {0}")]
+    UnmatchedBrace(usize),
+}
+
+pub type TemplateResult<T> = result::Result<T, TemplateError>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct FormatSpec {
+    fill: Option<char>,
+    align: Option<Align>,
+    zero: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+}
+
+impl FormatSpec {
+    fn parse(spec: &str) -> Option<Self> {
+        let mut result = Self::default();
+        let chars: Vec<char> = spec.chars().collect();
+        let mut pos = 0;
+        let to_align = |c: char| match c {
+            '<' => Some(Align::Left),
+            '^' => Some(Align::Center),
+            '>' => Some(Align::Right),
+            _ => None,
+        };
+        if let Some(align) = chars.get(1).and_then(|&c| to_align(c)) {
+            result.fill = Some(chars[0]);
+            result.align = Some(align);
+            pos = 2;
+        } else if let Some(align) = chars.first().and_then(|&c| to_align(c)) {
+            result.align = Some(align);
+            pos = 1;
+        }
+        if chars.get(pos) == Some(&'0') {
+            result.zero = true;
+            pos += 1;
+        }
+        let digits = |pos: &mut usize| {
+            let start = *pos;
+            while chars.get(*pos).is_some_and(char::is_ascii_digit) {
+                *pos += 1;
+            }
+            chars[start..*pos].iter().collect::<String>().parse().ok()
+        };
+        result.width = digits(&mut pos);
+        if chars.get(pos) == Some(&'.') {
+            pos += 1;
+            result.precision = Some(digits(&mut pos)?);
+        }
+        if pos == chars.len() {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    fn pad(&self, text: String, numeric: bool) -> String {
+        let len = text.chars().count();
+        let width = match self.width {
+            Some(width) if width > len => width,
+            _ => return text,
+        };
+        let padding = width - len;
+        if self.zero && numeric && self.align.is_none() {
+            let (sign, digits) = match text.strip_prefix('-') {
+                Some(digits) => ("-", digits),
+                None => ("", text.as_str()),
+            };
+            return format!("{}{}{}", sign, "0".repeat(padding), digits);
+        }
+        let fill = self.fill.unwrap_or(' ').to_string();
+        let align = self
+            .align
+            .unwrap_or(if numeric { Align::Right } else { Align::Left });
+        let (left, right) = match align {
+            Align::Left => (0, padding),
+            Align::Center => (padding / 2, padding - padding / 2),
+            Align::Right => (padding, 0),
+        };
+        format!("{}{}{}", fill.repeat(left), text, fill.repeat(right))
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Value {
+    Text(String),
+    Integer(i32),
+    Float(f32),
+}
+
+impl Value {
+    fn format(&self, spec: &FormatSpec) -> String {
+        match self {
+            Value::Text(text) => spec.pad(
+                match spec.precision {
+                    Some(precision) => text.chars().take(precision).collect(),
+                    None => text.clone(),
+                },
+                false,
+            ),
+            Value::Integer(value) => spec.pad(value.to_string(), true),
+            Value::Float(value) => spec.pad(
+                match spec.precision {
+                    Some(precision) => format!("{:.*}", precision, value),
+                    None => value.to_string(),
+                },
+                true,
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    Placeholder(String, FormatSpec),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    pub fn parse(text: &str) -> TemplateResult<Self> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = text.char_indices().peekable();
+        while let Some((pos, c)) = chars.next() {
+            match c {
+                '{' if chars.peek().map(|&(_, c)| c) == Some('{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek().map(|&(_, c)| c) == Some('}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '}' => return Err(TemplateError::UnmatchedBrace(pos)),
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some((_, '}')) => break,
+                            Some((_, c)) => placeholder.push(c),
+                            None => return Err(TemplateError::UnclosedPlaceholder(pos)),
+                        }
+                    }
+                    let (name, spec) = match placeholder.split_once(':') {
+                        Some((name, spec)) => (name.trim(), spec),
+                        None => (placeholder.trim(), ""),
+                    };
+                    if !TEMPLATE_FIELDS.contains(&name) {
+                        return Err(TemplateError::UnknownPlaceholder(name.to_string()));
+                    }
+                    let spec = FormatSpec::parse(spec).ok_or_else(|| {
+                        TemplateError::InvalidFormatSpec(name.to_string(), spec.to_string())
+                    })?;
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(literal.split_off(0)));
+                    }
+                    segments.push(Segment::Placeholder(name.to_string(), spec));
+                }
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        Ok(Self { segments })
+    }
+
+    pub fn render(
+        &self,
+        location: &Location,
+        weather: &Weather,
+        units: Units,
+        date_time_format: &DateTimeFormat,
+    ) -> String {
+        let weather = weather.convert(units);
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text.clone(),
+                Segment::Placeholder(name, spec) => {
+                    Self::value(name, location, &weather, units, date_time_format).format(spec)
+                }
+            })
+            .collect()
+    }
+
+    fn value(
+        name: &str,
+        location: &Location,
+        weather: &Weather,
+        units: Units,
+        date_time_format: &DateTimeFormat,
+    ) -> Value {
+        match name {
+            "city" => Value::Text(location.city.clone()),
+            "country" => Value::Text(location.country.clone()),
+            "region" => Value::Text(location.region.clone().unwrap_or_default()),
+            "country_code" => Value::Text(location.country_code.clone().unwrap_or_default()),
+            "postal_code" => Value::Text(location.postal_code.clone().unwrap_or_default()),
+            "coordinates.latitude" => Value::Float(location.coordinates.latitude),
+            "coordinates.longitude" => Value::Float(location.coordinates.longitude),
+            "temperature" => Value::Float(weather.temperature),
+            "units" => Value::Text(units.symbol().to_string()),
+            "icon" => Value::Text(weather.icon.clone()),
+            "description" => Value::Text(weather.description.clone()),
+            "feels_like" => Value::Float(weather.feels_like),
+            "min_temperature" => Value::Float(weather.min_temperature),
+            "max_temperature" => Value::Float(weather.max_temperature),
+            "pressure" => Value::Float(weather.pressure),
+            "humidity" => Value::Integer(weather.humidity),
+            "sea_level" => Value::Float(weather.sea_level.unwrap_or_default()),
+            "ground_level" => Value::Float(weather.ground_level.unwrap_or_default()),
+            "wind.speed" => Value::Float(weather.wind.speed),
+            "wind.degrees" => Value::Integer(weather.wind.degrees),
+            "wind.gust" => Value::Float(weather.wind.gust),
+            "clouds" => Value::Integer(weather.clouds),
+            "precipitation" => Value::Float(weather.precipitation.unwrap_or_default()),
+            "date_time" => Value::Text(weather.date_time.format(date_time_format)),
+            "sunrise" => Value::Text(weather.sunrise.format(date_time_format)),
+            "sunset" => Value::Text(weather.sunset.format(date_time_format)),
+            "solar_noon" => Value::Text(
+                weather
+                    .solar_noon
+                    .as_ref()
+                    .map(|solar_noon| solar_noon.format(date_time_format))
+                    .unwrap_or_default(),
+            ),
+            "day_length" => Value::Integer(weather.day_length.unwrap_or_default() as i32),
+            "uv_index" => Value::Float(weather.uv_index.unwrap_or_default()),
+            _ => Value::Text(String::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Template, TemplateError};
+    use crate::{
+        datetime::{DateTime, DateTimeFormat},
+        fixtures::{self, location},
+        time_zone::TimeZone,
+        units::{PrecipitationUnit, PressureUnit, SpeedUnit, Units},
+        weather::model::Weather,
+    };
+
+    fn weather() -> Weather {
+        Weather {
+            ground_level: Some(949.0),
+            precipitation: Some(0.35),
+            ..fixtures::weather()
+        }
+    }
+
+    #[test]
+    fn template_render() {
+        let format = DateTimeFormat::default();
+        let (location, weather) = (location(), weather());
+        let template =
+            Template::parse("{city} - Temperature: {temperature}{units} - Humidity: {humidity}%")
+                .unwrap();
+        assert_eq!(
+            template.render(&location, &weather, Units::metric(), &format),
+            "Monteiro - Temperature: 25.8C - Humidity: 55%"
+        );
+        assert_eq!(
+            template.render(&location, &weather, Units::imperial(), &format),
+            "Monteiro - Temperature: 78.44F - Humidity: 55%"
+        );
+
+        let template = Template::parse("{wind.speed} {pressure} {precipitation}").unwrap();
+        assert_eq!(
+            template.render(&location, &weather, Units::metric(), &format),
+            "4.72 1017 0.35"
+        );
+        let units = Units {
+            wind_speed: SpeedUnit::Knots,
+            pressure: PressureUnit::InchesOfMercury,
+            precipitation: PrecipitationUnit::Inches,
+            ..Units::imperial()
+        };
+        assert_eq!(
+            template.render(&location, &weather, units, &format),
+            "9.17 30.03 0.01"
+        );
+
+        let template = Template::parse(
+            "{coordinates.latitude},{coordinates.longitude} {wind.speed}/{wind.degrees}/{wind.gust} {sunrise} {sunset}",
+        )
+        .unwrap();
+        assert_eq!(
+            template.render(&location, &weather, Units::metric(), &format),
+            "-7.9194,-37.175 4.72/115/6.14 2021-09-14T08:22:49Z 2021-09-14T20:25:52Z"
+        );
+
+        let template = Template::parse("{{{city}}} {icon} {description}").unwrap();
+        assert_eq!(
+            template.render(&location, &weather, Units::metric(), &format),
+            "{Monteiro} ☀\u{fe0f} Scattered clouds"
+        );
+        assert_eq!(
+            Template::parse("")
+                .unwrap()
+                .render(&location, &weather, Units::metric(), &format),
+            ""
+        );
+    }
+
+    #[test]
+    fn template_render_date_time_format() {
+        let location = location();
+        let mut weather = weather();
+        weather.date_time = weather.date_time.set_offset(-10800);
+        weather.sunrise = weather.sunrise.set_offset(-10800);
+        let template = Template::parse("{date_time} {sunrise}").unwrap();
+        let render = |format| template.render(&location, &weather, Units::metric(), &format);
+        assert_eq!(
+            render(DateTimeFormat::default()),
+            "2021-09-14T08:57:26-03:00 2021-09-14T05:22:49-03:00"
+        );
+        assert_eq!(
+            render(DateTimeFormat::new(TimeZone::Utc, None)),
+            "2021-09-14T11:57:26Z 2021-09-14T08:22:49Z"
+        );
+        assert_eq!(
+            render(DateTimeFormat::new(
+                TimeZone::Location,
+                Some("%H:%M".to_string())
+            )),
+            "08:57 05:22"
+        );
+    }
+
+    #[test]
+    fn template_render_solar_fields() {
+        let format = DateTimeFormat::default();
+        let location = location();
+        let template = Template::parse("{solar_noon}|{day_length}|{uv_index:.1}").unwrap();
+        let mut weather = weather();
+        assert_eq!(
+            template.render(&location, &weather, Units::metric(), &format),
+            "|0|0.0"
+        );
+        weather.solar_noon = Some(DateTime::from_unix(1631629460).set_offset(-10800));
+        weather.day_length = Some(43383);
+        weather.uv_index = Some(6.8);
+        assert_eq!(
+            template.render(&location, &weather, Units::metric(), &format),
+            "2021-09-14T11:24:20-03:00|43383|6.8"
+        );
+    }
+
+    #[test]
+    fn template_render_format_spec() {
+        let format = DateTimeFormat::default();
+        let (location, weather) = (location(), weather());
+        let render = |text: &str| {
+            Template::parse(text)
+                .unwrap()
+                .render(&location, &weather, Units::metric(), &format)
+        };
+        assert_eq!(render("{temperature:.0}"), "26");
+        assert_eq!(render("{feels_like:.1}"), "25.9");
+        assert_eq!(render("{coordinates.latitude:.2}"), "-7.92");
+        assert_eq!(render("[{city:12}]"), "[Monteiro    ]");
+        assert_eq!(render("[{city:>12}]"), "[    Monteiro]");
+        assert_eq!(render("[{city:*^12}]"), "[**Monteiro**]");
+        assert_eq!(render("[{city:.3}]"), "[Mon]");
+        assert_eq!(render("[{humidity:5}]"), "[   55]");
+        assert_eq!(render("[{humidity:<5}]"), "[55   ]");
+        assert_eq!(render("[{humidity:05}]"), "[00055]");
+        assert_eq!(render("[{coordinates.latitude:08.2}]"), "[-0007.92]");
+        assert_eq!(render("[{temperature:6.1}]"), "[  25.8]");
+    }
+
+    #[test]
+    fn template_parse_errors() {
+        assert_eq!(
+            Template::parse("{city} {temp}"),
+            Err(TemplateError::UnknownPlaceholder("temp".to_string()))
+        );
+        assert_eq!(
+            Template::parse("{wind.direction}"),
+            Err(TemplateError::UnknownPlaceholder(
+                "wind.direction".to_string()
+            ))
+        );
+        assert_eq!(
+            Template::parse("{temperature:x}"),
+            Err(TemplateError::InvalidFormatSpec(
+                "temperature".to_string(),
+                "x".to_string()
+            ))
+        );
+        assert_eq!(
+            Template::parse("{temperature:.}"),
+            Err(TemplateError::InvalidFormatSpec(
+                "temperature".to_string(),
+                ".".to_string()
+            ))
+        );
+        assert_eq!(
+            Template::parse("{city"),
+            Err(TemplateError::UnclosedPlaceholder(0))
+        );
+        assert_eq!(
+            Template::parse("city}"),
+            Err(TemplateError::UnmatchedBrace(4))
+        );
+        assert!(Template::parse("{temp}")
+            .unwrap_err()
+            .to_string()
+            .starts_with("Unknown template placeholder `{temp}`, available: city, country,"));
+    }
+}
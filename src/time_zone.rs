@@ -0,0 +1,105 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    result,
+    str::FromStr,
+};
+
+use chrono::{Local, TimeZone as _};
+use serde::{de, Deserialize, Deserializer};
+use thiserror::Error;
+
+use crate::datetime::DateTime;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TimeZone {
+    #[default]
+    Location,
+    System,
+    Utc,
+}
+
+impl TimeZone {
+    pub fn offset(&self, date_time: &DateTime) -> i32 {
+        match *self {
+            Self::Location => date_time.offset(),
+            Self::System => system_offset(date_time.unix()),
+            Self::Utc => 0,
+        }
+    }
+}
+
+fn system_offset(unix: u64) -> i32 {
+    Local
+        .timestamp_opt(unix as i64, 0)
+        .single()
+        .map(|date_time| date_time.offset().local_minus_utc())
+        .unwrap_or_default()
+}
+
+impl Display for TimeZone {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match *self {
+            Self::Location => write!(fmt, "location"),
+            Self::System => write!(fmt, "system"),
+            Self::Utc => write!(fmt, "utc"),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid time zone `{0}`, expected location, system or utc")]
+pub struct TimeZoneParseError(String);
+
+impl FromStr for TimeZone {
+    type Err = TimeZoneParseError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "location" => Ok(Self::Location),
+            "system" | "local" => Ok(Self::System),
+            "utc" | "gmt" => Ok(Self::Utc),
+            _ => Err(TimeZoneParseError(value.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeZone {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TimeZone, TimeZoneParseError};
+    use crate::datetime::DateTime;
+
+    #[test]
+    fn time_zone_to_string() {
+        assert_eq!(TimeZone::Location.to_string(), "location");
+        assert_eq!(TimeZone::System.to_string(), "system");
+        assert_eq!(TimeZone::Utc.to_string(), "utc");
+    }
+
+    #[test]
+    fn time_zone_from_str() {
+        assert_eq!("location".parse(), Ok(TimeZone::Location));
+        assert_eq!("System".parse(), Ok(TimeZone::System));
+        assert_eq!("local".parse(), Ok(TimeZone::System));
+        assert_eq!("UTC".parse(), Ok(TimeZone::Utc));
+        assert_eq!(
+            "mars".parse::<TimeZone>(),
+            Err(TimeZoneParseError("mars".to_string()))
+        );
+    }
+
+    #[test]
+    fn time_zone_offset() {
+        let date_time = DateTime::from_unix(1631620646).set_offset(-10800);
+        assert_eq!(TimeZone::Location.offset(&date_time), -10800);
+        assert_eq!(TimeZone::Utc.offset(&date_time), 0);
+        assert!(TimeZone::System.offset(&date_time).abs() <= 14 * 3600);
+    }
+}
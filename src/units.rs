@@ -1,49 +1,461 @@
-use std::fmt::{Display, Formatter, Result};
+use std::{
+    fmt::{Display, Formatter, Result},
+    result,
+    str::FromStr,
+};
 
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub enum Units {
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+const BEAUFORT_LIMITS: [f32; 12] = [
+    0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7,
+];
+
+fn round(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UnitSystem {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+impl Display for UnitSystem {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match *self {
+            Self::Metric => write!(fmt, "metric"),
+            Self::Imperial => write!(fmt, "imperial"),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid units `{0}`, expected C (metric) or F (imperial)")]
+pub struct UnitSystemParseError(String);
+
+impl FromStr for UnitSystem {
+    type Err = UnitSystemParseError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "c" | "celsius" | "metric" => Ok(Self::Metric),
+            "f" | "fahrenheit" | "imperial" => Ok(Self::Imperial),
+            _ => Err(UnitSystemParseError(value.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UnitSystem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TemperatureUnit {
+    #[default]
     Celsius,
     Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    pub fn convert(&self, celsius: f32) -> f32 {
+        match *self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => round(celsius * 9.0 / 5.0 + 32.0),
+            Self::Kelvin => round(celsius + 273.15),
+        }
+    }
+}
+
+impl Display for TemperatureUnit {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match *self {
+            Self::Celsius => write!(fmt, "C"),
+            Self::Fahrenheit => write!(fmt, "F"),
+            Self::Kelvin => write!(fmt, "K"),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid temperature unit `{0}`, expected C, F or K")]
+pub struct TemperatureUnitParseError(String);
+
+impl FromStr for TemperatureUnit {
+    type Err = TemperatureUnitParseError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "c" | "celsius" => Ok(Self::Celsius),
+            "f" | "fahrenheit" => Ok(Self::Fahrenheit),
+            "k" | "kelvin" => Ok(Self::Kelvin),
+            _ => Err(TemperatureUnitParseError(value.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TemperatureUnit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for TemperatureUnit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
 }
 
-impl Default for Units {
-    fn default() -> Self {
-        Self::Celsius
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SpeedUnit {
+    #[default]
+    MetersPerSecond,
+    KilometersPerHour,
+    MilesPerHour,
+    Knots,
+    Beaufort,
+}
+
+impl SpeedUnit {
+    pub fn convert(&self, meters_per_second: f32) -> f32 {
+        match *self {
+            Self::MetersPerSecond => meters_per_second,
+            Self::KilometersPerHour => round(meters_per_second * 3.6),
+            Self::MilesPerHour => round(meters_per_second * 2.236_936),
+            Self::Knots => round(meters_per_second * 1.943_844),
+            Self::Beaufort => BEAUFORT_LIMITS
+                .iter()
+                .filter(|&&limit| meters_per_second >= limit)
+                .count() as f32,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match *self {
+            Self::MetersPerSecond => "meter/sec",
+            Self::KilometersPerHour => "km/hour",
+            Self::MilesPerHour => "miles/hour",
+            Self::Knots => "knots",
+            Self::Beaufort => "Beaufort",
+        }
     }
 }
 
-impl Display for Units {
+impl Display for SpeedUnit {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
         match *self {
-            Self::Celsius => write!(fmt, "metric"),
-            Self::Fahrenheit => write!(fmt, "imperial"),
+            Self::MetersPerSecond => write!(fmt, "m/s"),
+            Self::KilometersPerHour => write!(fmt, "km/h"),
+            Self::MilesPerHour => write!(fmt, "mph"),
+            Self::Knots => write!(fmt, "knots"),
+            Self::Beaufort => write!(fmt, "beaufort"),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid wind speed unit `{0}`, expected m/s, km/h, mph, knots or beaufort")]
+pub struct SpeedUnitParseError(String);
+
+impl FromStr for SpeedUnit {
+    type Err = SpeedUnitParseError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "m/s" | "ms" | "mps" => Ok(Self::MetersPerSecond),
+            "km/h" | "kmh" | "kph" => Ok(Self::KilometersPerHour),
+            "mph" => Ok(Self::MilesPerHour),
+            "knots" | "knot" | "kn" | "kt" => Ok(Self::Knots),
+            "beaufort" | "bft" => Ok(Self::Beaufort),
+            _ => Err(SpeedUnitParseError(value.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SpeedUnit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for SpeedUnit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PressureUnit {
+    #[default]
+    Hectopascals,
+    InchesOfMercury,
+    MillimetersOfMercury,
+    Kilopascals,
+}
+
+impl PressureUnit {
+    pub fn convert(&self, hectopascals: f32) -> f32 {
+        match *self {
+            Self::Hectopascals => hectopascals,
+            Self::InchesOfMercury => round(hectopascals * 0.029_53),
+            Self::MillimetersOfMercury => round(hectopascals * 0.750_062),
+            Self::Kilopascals => round(hectopascals / 10.0),
+        }
+    }
+}
+
+impl Display for PressureUnit {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match *self {
+            Self::Hectopascals => write!(fmt, "hPa"),
+            Self::InchesOfMercury => write!(fmt, "inHg"),
+            Self::MillimetersOfMercury => write!(fmt, "mmHg"),
+            Self::Kilopascals => write!(fmt, "kPa"),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid pressure unit `{0}`, expected hPa, inHg, mmHg or kPa")]
+pub struct PressureUnitParseError(String);
+
+impl FromStr for PressureUnit {
+    type Err = PressureUnitParseError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "hpa" | "mb" | "mbar" => Ok(Self::Hectopascals),
+            "inhg" => Ok(Self::InchesOfMercury),
+            "mmhg" => Ok(Self::MillimetersOfMercury),
+            "kpa" => Ok(Self::Kilopascals),
+            _ => Err(PressureUnitParseError(value.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PressureUnit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for PressureUnit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PrecipitationUnit {
+    #[default]
+    Millimeters,
+    Inches,
+}
+
+impl PrecipitationUnit {
+    pub fn convert(&self, millimeters: f32) -> f32 {
+        match *self {
+            Self::Millimeters => millimeters,
+            Self::Inches => round(millimeters / 25.4),
+        }
+    }
+}
+
+impl Display for PrecipitationUnit {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        match *self {
+            Self::Millimeters => write!(fmt, "mm"),
+            Self::Inches => write!(fmt, "in"),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid precipitation unit `{0}`, expected mm or in")]
+pub struct PrecipitationUnitParseError(String);
+
+impl FromStr for PrecipitationUnit {
+    type Err = PrecipitationUnitParseError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "mm" | "millimeters" => Ok(Self::Millimeters),
+            "in" | "inches" => Ok(Self::Inches),
+            _ => Err(PrecipitationUnitParseError(value.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PrecipitationUnit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for PrecipitationUnit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Units {
+    pub temperature: TemperatureUnit,
+    pub wind_speed: SpeedUnit,
+    pub pressure: PressureUnit,
+    pub precipitation: PrecipitationUnit,
+}
+
+impl From<UnitSystem> for Units {
+    fn from(system: UnitSystem) -> Self {
+        match system {
+            UnitSystem::Metric => Self::default(),
+            UnitSystem::Imperial => Self {
+                temperature: TemperatureUnit::Fahrenheit,
+                wind_speed: SpeedUnit::MilesPerHour,
+                ..Self::default()
+            },
         }
     }
 }
 
 impl Units {
-    pub fn symbol(&self) -> &'static str {
-        if *self == Self::Celsius {
-            "C"
+    pub fn metric() -> Self {
+        UnitSystem::Metric.into()
+    }
+
+    pub fn imperial() -> Self {
+        UnitSystem::Imperial.into()
+    }
+
+    pub fn system(&self) -> &'static str {
+        if *self == Self::metric() {
+            "metric"
+        } else if *self == Self::imperial() {
+            "imperial"
         } else {
-            "F"
+            "custom"
         }
     }
+
+    pub fn symbol(&self) -> String {
+        self.temperature.to_string()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Units;
+    use super::{
+        PrecipitationUnit, PressureUnit, SpeedUnit, SpeedUnitParseError, TemperatureUnit,
+        UnitSystem, UnitSystemParseError, Units,
+    };
 
     #[test]
-    fn units_to_string() {
-        assert_eq!(Units::Celsius.to_string(), "metric");
-        assert_eq!(Units::Fahrenheit.to_string(), "imperial");
+    fn unit_system_to_string() {
+        assert_eq!(UnitSystem::Metric.to_string(), "metric");
+        assert_eq!(UnitSystem::Imperial.to_string(), "imperial");
+    }
+
+    #[test]
+    fn unit_system_from_str() {
+        assert_eq!("C".parse(), Ok(UnitSystem::Metric));
+        assert_eq!("celsius".parse(), Ok(UnitSystem::Metric));
+        assert_eq!("Metric".parse(), Ok(UnitSystem::Metric));
+        assert_eq!("f".parse(), Ok(UnitSystem::Imperial));
+        assert_eq!("Fahrenheit".parse(), Ok(UnitSystem::Imperial));
+        assert_eq!("imperial".parse(), Ok(UnitSystem::Imperial));
+        assert_eq!(
+            "K".parse::<UnitSystem>(),
+            Err(UnitSystemParseError("K".to_string()))
+        );
+    }
+
+    #[test]
+    fn units_from_system() {
+        let units = Units::metric();
+        assert_eq!(units.symbol(), "C");
+        assert_eq!(units.wind_speed.label(), "meter/sec");
+        assert_eq!(units.pressure.to_string(), "hPa");
+        assert_eq!(units.precipitation.to_string(), "mm");
+        assert_eq!(units.system(), "metric");
+        let units = Units::imperial();
+        assert_eq!(units.symbol(), "F");
+        assert_eq!(units.wind_speed.label(), "miles/hour");
+        assert_eq!(units.pressure.to_string(), "hPa");
+        assert_eq!(units.precipitation.to_string(), "mm");
+        assert_eq!(units.system(), "imperial");
+        let units = Units {
+            wind_speed: SpeedUnit::Knots,
+            ..Units::metric()
+        };
+        assert_eq!(units.system(), "custom");
+    }
+
+    #[test]
+    fn units_convert() {
+        assert_eq!(TemperatureUnit::Celsius.convert(25.87), 25.87);
+        assert_eq!(TemperatureUnit::Fahrenheit.convert(25.0), 77.0);
+        assert_eq!(TemperatureUnit::Fahrenheit.convert(-40.0), -40.0);
+        assert_eq!(TemperatureUnit::Kelvin.convert(25.8), 298.95);
+        assert_eq!(SpeedUnit::MetersPerSecond.convert(4.72), 4.72);
+        assert_eq!(SpeedUnit::KilometersPerHour.convert(10.0), 36.0);
+        assert_eq!(SpeedUnit::MilesPerHour.convert(10.0), 22.37);
+        assert_eq!(SpeedUnit::Knots.convert(4.72), 9.17);
+        assert_eq!(SpeedUnit::Beaufort.convert(0.2), 0.0);
+        assert_eq!(SpeedUnit::Beaufort.convert(4.72), 3.0);
+        assert_eq!(SpeedUnit::Beaufort.convert(40.0), 12.0);
+        assert_eq!(PressureUnit::Hectopascals.convert(1017.0), 1017.0);
+        assert_eq!(PressureUnit::InchesOfMercury.convert(1017.0), 30.03);
+        assert_eq!(PressureUnit::MillimetersOfMercury.convert(1017.0), 762.81);
+        assert_eq!(PressureUnit::Kilopascals.convert(1017.0), 101.7);
+        assert_eq!(PrecipitationUnit::Millimeters.convert(2.5), 2.5);
+        assert_eq!(PrecipitationUnit::Inches.convert(25.4), 1.0);
+    }
+
+    #[test]
+    fn units_from_str() {
+        assert_eq!("K".parse(), Ok(TemperatureUnit::Kelvin));
+        assert_eq!("fahrenheit".parse(), Ok(TemperatureUnit::Fahrenheit));
+        assert_eq!("kn".parse(), Ok(SpeedUnit::Knots));
+        assert_eq!("km/h".parse(), Ok(SpeedUnit::KilometersPerHour));
+        assert_eq!("Bft".parse(), Ok(SpeedUnit::Beaufort));
+        assert_eq!(
+            "furlongs".parse::<SpeedUnit>(),
+            Err(SpeedUnitParseError("furlongs".to_string()))
+        );
+        assert_eq!("inHg".parse(), Ok(PressureUnit::InchesOfMercury));
+        assert_eq!("mbar".parse(), Ok(PressureUnit::Hectopascals));
+        assert_eq!("in".parse(), Ok(PrecipitationUnit::Inches));
+        for unit in &["m/s", "km/h", "mph", "knots", "beaufort"] {
+            assert_eq!(unit.parse::<SpeedUnit>().unwrap().to_string(), *unit);
+        }
     }
 
     #[test]
-    fn units_symbol() {
-        assert_eq!(Units::Celsius.symbol(), "C");
-        assert_eq!(Units::Fahrenheit.symbol(), "F");
+    fn units_serde() {
+        let units = Units {
+            wind_speed: SpeedUnit::Knots,
+            ..Units::metric()
+        };
+        let json = serde_json::to_string(&units).unwrap();
+        assert_eq!(
+            json,
+            "{\"temperature\":\"C\",\"wind_speed\":\"knots\",\"pressure\":\"hPa\",\"precipitation\":\"mm\"}"
+        );
+        assert_eq!(serde_json::from_str::<Units>(&json).unwrap(), units);
     }
 }
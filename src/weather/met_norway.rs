@@ -0,0 +1,415 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::{
+    client::Client,
+    datetime::DateTime,
+    location::model::Coordinates,
+    solar::SolarDay,
+    weather::{
+        client::WeatherClientError,
+        model::{Weather, Wind},
+        openweathermap::WeatherMap,
+        provider::{WeatherProvider, WeatherProviderKind},
+    },
+};
+
+pub const URL_MET_NORWAY: &str = "https://api.met.no/weatherapi/locationforecast/2.0/compact";
+
+pub(crate) fn symbol_description(symbol_code: &str) -> String {
+    let symbol = match symbol_code.split('_').next().unwrap_or_default() {
+        // MET Norway spells these two symbols with a double "s".
+        "lightssleetshowersandthunder" => "lightsleetshowersandthunder",
+        "lightssnowshowersandthunder" => "lightsnowshowersandthunder",
+        symbol => symbol,
+    };
+    match symbol {
+        "clearsky" => return "clear sky".to_string(),
+        "fair" => return "few clouds".to_string(),
+        "partlycloudy" => return "scattered clouds".to_string(),
+        "cloudy" => return "overcast clouds".to_string(),
+        "fog" => return "fog".to_string(),
+        _ => {}
+    }
+    let (symbol, thunder) = match symbol.strip_suffix("andthunder") {
+        Some(symbol) => (symbol, true),
+        None => (symbol, false),
+    };
+    let (symbol, showers) = match symbol.strip_suffix("showers") {
+        Some(symbol) => (symbol, true),
+        None => (symbol, false),
+    };
+    let (intensity, precipitation) = if let Some(symbol) = symbol.strip_prefix("light") {
+        ("light ", symbol)
+    } else if let Some(symbol) = symbol.strip_prefix("heavy") {
+        ("heavy ", symbol)
+    } else {
+        ("", symbol)
+    };
+    if !matches!(precipitation, "rain" | "sleet" | "snow") {
+        return String::new();
+    }
+    let description = format!(
+        "{}{}{}",
+        intensity,
+        precipitation,
+        if showers { " showers" } else { "" }
+    );
+    if thunder {
+        format!("thunderstorm with {}", description)
+    } else {
+        description
+    }
+}
+
+// Wind chill below 10°C (Environment Canada) and heat index from 80°F (NWS Rothfusz).
+pub(crate) fn apparent_temperature(
+    temperature: f32,
+    humidity: Option<f32>,
+    wind_speed: Option<f32>,
+) -> f32 {
+    let wind_speed = wind_speed.unwrap_or_default() * 3.6;
+    let fahrenheit = temperature * 9.0 / 5.0 + 32.0;
+    let value = match humidity {
+        _ if temperature <= 10.0 && wind_speed > 4.8 => {
+            let wind = wind_speed.powf(0.16);
+            13.12 + 0.6215 * temperature - 11.37 * wind + 0.3965 * temperature * wind
+        }
+        Some(humidity) if fahrenheit >= 80.0 => {
+            let (t, rh) = (fahrenheit, humidity);
+            let index = -42.379 + 2.049_015_2 * t + 10.143_331 * rh
+                - 0.224_755_4 * t * rh
+                - 0.006_837_83 * t * t
+                - 0.054_817_17 * rh * rh
+                + 0.001_228_74 * t * t * rh
+                + 0.000_852_82 * t * rh * rh
+                - 0.000_001_99 * t * t * rh * rh;
+            (index - 32.0) * 5.0 / 9.0
+        }
+        _ => return temperature,
+    };
+    (value * 10.0).round() / 10.0
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct MetInstantDetails {
+    air_temperature: f32,
+    air_pressure_at_sea_level: Option<f32>,
+    relative_humidity: Option<f32>,
+    cloud_area_fraction: Option<f32>,
+    wind_speed: Option<f32>,
+    wind_from_direction: Option<f32>,
+    wind_speed_of_gust: Option<f32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct MetInstant {
+    details: MetInstantDetails,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct MetSummary {
+    symbol_code: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct MetPeriodDetails {
+    air_temperature_max: Option<f32>,
+    air_temperature_min: Option<f32>,
+    precipitation_amount: Option<f32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct MetPeriod {
+    summary: Option<MetSummary>,
+    details: Option<MetPeriodDetails>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct MetData {
+    instant: MetInstant,
+    next_1_hours: Option<MetPeriod>,
+    next_6_hours: Option<MetPeriod>,
+    next_12_hours: Option<MetPeriod>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct MetTimeStep {
+    time: DateTime,
+    data: MetData,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct MetProperties {
+    timeseries: Vec<MetTimeStep>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct MetResponse {
+    properties: MetProperties,
+}
+
+impl MetResponse {
+    fn into_weather(self, coordinates: &Coordinates) -> Result<Weather, WeatherClientError> {
+        let (time, data) = match self.properties.timeseries.into_iter().next() {
+            Some(step) => (step.time.set_offset(coordinates.offset()), step.data),
+            None => return Err(WeatherClientError::NoData(WeatherProviderKind::MetNorway)),
+        };
+        let details = data.instant.details;
+        let symbol_code = [&data.next_1_hours, &data.next_6_hours, &data.next_12_hours]
+            .iter()
+            .find_map(|period| period.as_ref()?.summary.as_ref())
+            .map(|summary| summary.symbol_code.as_str())
+            .unwrap_or_default();
+        let weather = WeatherMap {
+            description: symbol_description(symbol_code),
+        };
+        let precipitation = data
+            .next_1_hours
+            .and_then(|period| period.details)
+            .and_then(|details| details.precipitation_amount);
+        let period = data
+            .next_6_hours
+            .and_then(|period| period.details)
+            .unwrap_or_default();
+        let pressure = details.air_pressure_at_sea_level.map(f32::round);
+        let (sunrise, sunset) = SolarDay::new(coordinates, &time)
+            .sunrise_sunset()
+            .unwrap_or_else(|| (time.clone(), time.clone()));
+        let temperature = details.air_temperature;
+        Ok(Weather {
+            temperature,
+            icon: weather.icon(),
+            description: weather.format_description(),
+            feels_like: apparent_temperature(
+                temperature,
+                details.relative_humidity,
+                details.wind_speed,
+            ),
+            min_temperature: period.air_temperature_min.unwrap_or(temperature),
+            max_temperature: period.air_temperature_max.unwrap_or(temperature),
+            pressure: pressure.unwrap_or_default(),
+            humidity: details.relative_humidity.unwrap_or_default().round() as i32,
+            sea_level: pressure,
+            ground_level: None,
+            wind: Wind {
+                speed: details.wind_speed.unwrap_or_default(),
+                degrees: details.wind_from_direction.unwrap_or_default().round() as i32,
+                gust: details.wind_speed_of_gust.unwrap_or_default(),
+            },
+            clouds: details.cloud_area_fraction.unwrap_or_default().round() as i32,
+            date_time: time,
+            sunrise,
+            sunset,
+            solar_noon: None,
+            day_length: None,
+            // MET only reports the clear-sky UV index, which overstates cloudy days.
+            uv_index: None,
+            precipitation,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MetNorway;
+
+impl MetNorway {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl WeatherProvider for MetNorway {
+    async fn get(
+        &self,
+        client: &Client,
+        coordinates: &Coordinates,
+    ) -> Result<Weather, WeatherClientError> {
+        let url = format!(
+            "{}?lat={lat:.4}&lon={lon:.4}",
+            URL_MET_NORWAY,
+            lat = coordinates.latitude,
+            lon = coordinates.longitude
+        );
+        let res: MetResponse = client.get(&url).await?;
+        res.into_weather(coordinates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apparent_temperature, symbol_description, MetResponse};
+    use crate::location::model::Coordinates;
+
+    #[test]
+    fn met_norway_symbol_description() {
+        assert_eq!(symbol_description("clearsky_day"), "clear sky");
+        assert_eq!(symbol_description("fair_night"), "few clouds");
+        assert_eq!(
+            symbol_description("partlycloudy_polartwilight"),
+            "scattered clouds"
+        );
+        assert_eq!(symbol_description("cloudy"), "overcast clouds");
+        assert_eq!(symbol_description("fog"), "fog");
+        assert_eq!(symbol_description("lightrain"), "light rain");
+        assert_eq!(
+            symbol_description("heavysnowshowers_day"),
+            "heavy snow showers"
+        );
+        assert_eq!(
+            symbol_description("rainshowersandthunder_night"),
+            "thunderstorm with rain showers"
+        );
+        assert_eq!(symbol_description("sleet"), "sleet");
+        assert_eq!(symbol_description("unknown"), "");
+    }
+
+    #[test]
+    fn met_norway_symbol_list() {
+        let symbols = [
+            ("clearsky", "clear sky"),
+            ("cloudy", "overcast clouds"),
+            ("fair", "few clouds"),
+            ("fog", "fog"),
+            ("heavyrain", "heavy rain"),
+            ("heavyrainandthunder", "thunderstorm with heavy rain"),
+            ("heavyrainshowers", "heavy rain showers"),
+            (
+                "heavyrainshowersandthunder",
+                "thunderstorm with heavy rain showers",
+            ),
+            ("heavysleet", "heavy sleet"),
+            ("heavysleetandthunder", "thunderstorm with heavy sleet"),
+            ("heavysleetshowers", "heavy sleet showers"),
+            (
+                "heavysleetshowersandthunder",
+                "thunderstorm with heavy sleet showers",
+            ),
+            ("heavysnow", "heavy snow"),
+            ("heavysnowandthunder", "thunderstorm with heavy snow"),
+            ("heavysnowshowers", "heavy snow showers"),
+            (
+                "heavysnowshowersandthunder",
+                "thunderstorm with heavy snow showers",
+            ),
+            ("lightrain", "light rain"),
+            ("lightrainandthunder", "thunderstorm with light rain"),
+            ("lightrainshowers", "light rain showers"),
+            (
+                "lightrainshowersandthunder",
+                "thunderstorm with light rain showers",
+            ),
+            ("lightsleet", "light sleet"),
+            ("lightsleetandthunder", "thunderstorm with light sleet"),
+            ("lightsleetshowers", "light sleet showers"),
+            ("lightsnow", "light snow"),
+            ("lightsnowandthunder", "thunderstorm with light snow"),
+            ("lightsnowshowers", "light snow showers"),
+            (
+                "lightssleetshowersandthunder",
+                "thunderstorm with light sleet showers",
+            ),
+            (
+                "lightssnowshowersandthunder",
+                "thunderstorm with light snow showers",
+            ),
+            ("partlycloudy", "scattered clouds"),
+            ("rain", "rain"),
+            ("rainandthunder", "thunderstorm with rain"),
+            ("rainshowers", "rain showers"),
+            ("rainshowersandthunder", "thunderstorm with rain showers"),
+            ("sleet", "sleet"),
+            ("sleetandthunder", "thunderstorm with sleet"),
+            ("sleetshowers", "sleet showers"),
+            ("sleetshowersandthunder", "thunderstorm with sleet showers"),
+            ("snow", "snow"),
+            ("snowandthunder", "thunderstorm with snow"),
+            ("snowshowers", "snow showers"),
+            ("snowshowersandthunder", "thunderstorm with snow showers"),
+        ];
+        for (symbol, description) in &symbols {
+            assert_eq!(symbol_description(symbol), *description, "{}", symbol);
+        }
+    }
+
+    #[test]
+    fn met_norway_apparent_temperature() {
+        assert_eq!(apparent_temperature(25.0, Some(55.4), Some(4.7)), 25.0);
+        assert_eq!(apparent_temperature(-5.0, Some(80.0), Some(5.0)), -11.2);
+        assert_eq!(apparent_temperature(-5.0, None, Some(1.0)), -5.0);
+        assert_eq!(apparent_temperature(32.0, Some(60.0), Some(2.0)), 37.1);
+        assert_eq!(apparent_temperature(32.0, None, None), 32.0);
+    }
+
+    #[test]
+    fn weather_from_met_norway_response() {
+        let json = "{
+            \"type\": \"Feature\",
+            \"properties\": {
+              \"meta\": { \"updated_at\": \"2021-09-14T11:30:00Z\" },
+              \"timeseries\": [
+                {
+                  \"time\": \"2021-09-14T12:00:00Z\",
+                  \"data\": {
+                    \"instant\": {
+                      \"details\": {
+                        \"air_pressure_at_sea_level\": 1016.8,
+                        \"air_temperature\": 25.0,
+                        \"cloud_area_fraction\": 46.1,
+                        \"relative_humidity\": 55.4,
+                        \"wind_from_direction\": 115.3,
+                        \"wind_speed\": 4.7,
+                        \"ultraviolet_index_clear_sky\": 9.1
+                      }
+                    },
+                    \"next_1_hours\": {
+                      \"summary\": { \"symbol_code\": \"lightrainshowers_day\" },
+                      \"details\": { \"precipitation_amount\": 0.2 }
+                    },
+                    \"next_6_hours\": {
+                      \"summary\": { \"symbol_code\": \"partlycloudy_day\" },
+                      \"details\": {
+                        \"air_temperature_max\": 28.0,
+                        \"air_temperature_min\": 24.0
+                      }
+                    }
+                  }
+                }
+              ]
+            }
+        }";
+        let coordinates = Coordinates::new(-7.9194, -37.175);
+        let response = serde_json::from_str::<MetResponse>(json).unwrap();
+        let weather = response.into_weather(&coordinates).unwrap();
+        assert_eq!(weather.temperature, 25.0);
+        assert_eq!(weather.icon, "🌧");
+        assert_eq!(weather.description, "Light rain showers");
+        assert_eq!(weather.feels_like, 25.0);
+        assert_eq!(weather.min_temperature, 24.0);
+        assert_eq!(weather.max_temperature, 28.0);
+        assert_eq!(weather.pressure, 1017.0);
+        assert_eq!(weather.humidity, 55);
+        assert_eq!(weather.sea_level, Some(1017.0));
+        assert_eq!(weather.ground_level, None);
+        assert_eq!(weather.wind.speed, 4.7);
+        assert_eq!(weather.wind.degrees, 115);
+        assert_eq!(weather.wind.gust, 0.0);
+        assert_eq!(weather.clouds, 46);
+        assert_eq!(weather.date_time.to_string(), "2021-09-14T10:00:00-02:00");
+        assert_eq!(weather.sunrise.date(), "2021-09-14");
+        assert_eq!(weather.sunrise.time(), "06:22");
+        assert_eq!(weather.sunset.time(), "18:25");
+        assert_eq!(weather.uv_index, None);
+        assert_eq!(weather.precipitation, Some(0.2));
+    }
+
+    #[test]
+    fn met_norway_empty_timeseries() {
+        let json = "{ \"properties\": { \"timeseries\": [] } }";
+        let coordinates = Coordinates::new(-7.9194, -37.175);
+        let response = serde_json::from_str::<MetResponse>(json).unwrap();
+        let error = response.into_weather(&coordinates).unwrap_err();
+        assert_eq!(error.to_string(), "No weather data returned by met-norway");
+    }
+}
@@ -1,2 +1,7 @@
 pub mod client;
+pub mod met_norway;
 pub mod model;
+pub mod nws;
+pub mod open_meteo;
+pub mod openweathermap;
+pub mod provider;
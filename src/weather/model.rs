@@ -1,6 +1,8 @@
-use crate::{datetime::DateTime, units::Units};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+use crate::{datetime::DateTime, solar::SolarDay, units::Units};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Wind {
     pub speed: f32,
     pub degrees: i32,
@@ -9,10 +11,14 @@ pub struct Wind {
 
 impl Wind {
     pub fn format_metric(units: Units) -> &'static str {
-        if units == Units::Celsius {
-            "meter/sec"
-        } else {
-            "miles/hour"
+        units.wind_speed.label()
+    }
+
+    pub fn convert(&self, units: Units) -> Self {
+        Self {
+            speed: units.wind_speed.convert(self.speed),
+            degrees: self.degrees,
+            gust: units.wind_speed.convert(self.gust),
         }
     }
 
@@ -25,7 +31,7 @@ impl Wind {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Weather {
     pub temperature: f32,
     pub icon: String,
@@ -33,35 +39,128 @@ pub struct Weather {
     pub feels_like: f32,
     pub min_temperature: f32,
     pub max_temperature: f32,
-    pub pressure: i32,
+    pub pressure: f32,
     pub humidity: i32,
-    pub sea_level: Option<i32>,
-    pub ground_level: Option<i32>,
+    pub sea_level: Option<f32>,
+    pub ground_level: Option<f32>,
     pub wind: Wind,
     pub clouds: i32,
     pub date_time: DateTime,
     pub sunrise: DateTime,
     pub sunset: DateTime,
+    #[serde(default)]
+    pub solar_noon: Option<DateTime>,
+    #[serde(default)]
+    pub day_length: Option<u64>,
+    #[serde(default)]
+    pub uv_index: Option<f32>,
+    #[serde(default)]
+    pub precipitation: Option<f32>,
+}
+
+impl Weather {
+    pub fn set_solar_day(mut self, solar_day: &SolarDay) -> Self {
+        self.solar_noon = Some(solar_day.noon());
+        self.day_length = Some(solar_day.day_length().as_secs());
+        self
+    }
+
+    pub fn convert(&self, units: Units) -> Self {
+        let temperature = |value| units.temperature.convert(value);
+        let pressure = |value| units.pressure.convert(value);
+        Self {
+            temperature: temperature(self.temperature),
+            feels_like: temperature(self.feels_like),
+            min_temperature: temperature(self.min_temperature),
+            max_temperature: temperature(self.max_temperature),
+            pressure: pressure(self.pressure),
+            sea_level: self.sea_level.map(pressure),
+            ground_level: self.ground_level.map(pressure),
+            wind: self.wind.convert(units),
+            precipitation: self
+                .precipitation
+                .map(|value| units.precipitation.convert(value)),
+            ..self.clone()
+        }
+    }
+
+    pub fn uv_risk(&self) -> Option<&'static str> {
+        self.uv_index.map(|uv_index| match uv_index.round() as i32 {
+            i32::MIN..=2 => "Low",
+            3..=5 => "Moderate",
+            6..=7 => "High",
+            8..=10 => "Very high",
+            _ => "Extreme",
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Wind;
-    use crate::units::Units::*;
+    use super::{Weather, Wind};
+    use crate::{
+        fixtures::{weather, wind},
+        units::{PrecipitationUnit, PressureUnit, SpeedUnit, Units},
+    };
 
     #[test]
     fn wind_format() {
-        assert_eq!(Wind::format_metric(Celsius), "meter/sec");
-        assert_eq!(Wind::format_metric(Fahrenheit), "miles/hour");
+        let (metric, imperial) = (Units::metric(), Units::imperial());
+        assert_eq!(Wind::format_metric(metric), "meter/sec");
+        assert_eq!(Wind::format_metric(imperial), "miles/hour");
 
-        let wind = Wind {
-            speed: 4.72,
-            degrees: 115,
-            gust: 6.14,
+        let wind = wind();
+        assert_eq!(wind.format_speed(metric), "4.72 meter/sec");
+        assert_eq!(wind.format_speed(imperial), "4.72 miles/hour");
+        assert_eq!(wind.format_gust(metric), "6.14 meter/sec");
+        assert_eq!(wind.format_gust(imperial), "6.14 miles/hour");
+        let knots = Units {
+            wind_speed: SpeedUnit::Knots,
+            ..metric
+        };
+        assert_eq!(wind.convert(knots).format_speed(knots), "9.17 knots");
+    }
+
+    #[test]
+    fn weather_convert() {
+        let weather = Weather {
+            precipitation: Some(2.54),
+            ..weather()
+        };
+        let converted = weather.convert(Units::metric());
+        assert_eq!(converted.temperature, 25.8);
+        assert_eq!(converted.pressure, 1017.0);
+        let converted = weather.convert(Units::imperial());
+        assert_eq!(converted.temperature, 78.44);
+        assert_eq!(converted.feels_like, 78.57);
+        assert_eq!(converted.pressure, 1017.0);
+        assert_eq!(converted.wind.speed, 10.56);
+        assert_eq!(converted.precipitation, Some(2.54));
+        let converted = weather.convert(Units {
+            pressure: PressureUnit::InchesOfMercury,
+            precipitation: PrecipitationUnit::Inches,
+            ..Units::imperial()
+        });
+        assert_eq!(converted.pressure, 30.03);
+        assert_eq!(converted.sea_level, Some(30.03));
+        assert_eq!(converted.ground_level, None);
+        assert_eq!(converted.precipitation, Some(0.1));
+        assert_eq!(converted.humidity, 55);
+    }
+
+    #[test]
+    fn weather_uv_risk() {
+        let mut weather = weather();
+        assert_eq!(weather.uv_risk(), None);
+        let risk = |weather: &mut Weather, uv_index| {
+            weather.uv_index = Some(uv_index);
+            weather.uv_risk()
         };
-        assert_eq!(wind.format_speed(Celsius), "4.72 meter/sec");
-        assert_eq!(wind.format_speed(Fahrenheit), "4.72 miles/hour");
-        assert_eq!(wind.format_gust(Celsius), "6.14 meter/sec");
-        assert_eq!(wind.format_gust(Fahrenheit), "6.14 miles/hour");
+        assert_eq!(risk(&mut weather, 0.0), Some("Low"));
+        assert_eq!(risk(&mut weather, 2.4), Some("Low"));
+        assert_eq!(risk(&mut weather, 2.6), Some("Moderate"));
+        assert_eq!(risk(&mut weather, 7.0), Some("High"));
+        assert_eq!(risk(&mut weather, 10.0), Some("Very high"));
+        assert_eq!(risk(&mut weather, 11.2), Some("Extreme"));
     }
 }
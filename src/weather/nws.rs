@@ -0,0 +1,490 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::{
+    client::{Client, ClientError},
+    datetime::DateTime,
+    location::model::Coordinates,
+    solar::SolarDay,
+    weather::{
+        client::WeatherClientError,
+        model::{Weather, Wind},
+        openweathermap::WeatherMap,
+        provider::{WeatherProvider, WeatherProviderKind},
+    },
+};
+
+pub const URL_NWS_POINTS: &str = "https://api.weather.gov/points";
+
+pub(crate) fn text_description(text: &str) -> String {
+    let text = text.to_lowercase();
+    match text.as_str() {
+        "clear" | "sunny" | "fair" => "clear sky",
+        "mostly clear" | "mostly sunny" | "a few clouds" => "few clouds",
+        "partly cloudy" | "partly sunny" => "scattered clouds",
+        "mostly cloudy" => "broken clouds",
+        "cloudy" | "overcast" => "overcast clouds",
+        _ => return text,
+    }
+    .to_string()
+}
+
+pub(crate) fn cloud_amount(amount: &str) -> i32 {
+    match amount {
+        "FEW" => 25,
+        "SCT" => 44,
+        "BKN" => 75,
+        "OVC" | "VV" => 100,
+        _ => 0,
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NwsValue {
+    unit_code: String,
+    value: Option<f32>,
+}
+
+impl NwsValue {
+    fn celsius(&self) -> Option<f32> {
+        let value = self.value?;
+        Some(match self.unit_code.as_str() {
+            "wmoUnit:degF" => (value - 32.0) * 5.0 / 9.0,
+            _ => value,
+        })
+    }
+
+    fn meters_per_second(&self) -> Option<f32> {
+        let value = self.value?;
+        Some(match self.unit_code.as_str() {
+            "wmoUnit:km_h-1" => value / 3.6,
+            _ => value,
+        })
+    }
+
+    fn hectopascals(&self) -> Option<f32> {
+        let value = self.value?;
+        Some(
+            match self.unit_code.as_str() {
+                "wmoUnit:Pa" => value / 100.0,
+                _ => value,
+            }
+            .round(),
+        )
+    }
+
+    fn millimeters(&self) -> Option<f32> {
+        let value = self.value?;
+        Some(match self.unit_code.as_str() {
+            "wmoUnit:m" => value * 1000.0,
+            _ => value,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NwsPointProperties {
+    forecast: String,
+    observation_stations: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct NwsPointResponse {
+    properties: NwsPointProperties,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NwsPeriod {
+    start_time: Option<DateTime>,
+    is_daytime: bool,
+    temperature: f32,
+    temperature_unit: String,
+    short_forecast: String,
+}
+
+impl NwsPeriod {
+    fn celsius(&self) -> f32 {
+        NwsValue {
+            unit_code: format!("wmoUnit:deg{}", self.temperature_unit),
+            value: Some(self.temperature),
+        }
+        .celsius()
+        .unwrap_or(self.temperature)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct NwsForecastProperties {
+    periods: Vec<NwsPeriod>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct NwsForecastResponse {
+    properties: NwsForecastProperties,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NwsStationsResponse {
+    observation_stations: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct NwsCloudLayer {
+    amount: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct NwsObservationProperties {
+    timestamp: Option<DateTime>,
+    text_description: String,
+    temperature: NwsValue,
+    wind_direction: NwsValue,
+    wind_speed: NwsValue,
+    wind_gust: NwsValue,
+    barometric_pressure: NwsValue,
+    sea_level_pressure: NwsValue,
+    relative_humidity: NwsValue,
+    wind_chill: NwsValue,
+    heat_index: NwsValue,
+    precipitation_last_hour: NwsValue,
+    cloud_layers: Vec<NwsCloudLayer>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct NwsObservationResponse {
+    properties: NwsObservationProperties,
+}
+
+fn into_weather(
+    forecast: NwsForecastResponse,
+    observation: NwsObservationResponse,
+    coordinates: &Coordinates,
+) -> Weather {
+    let periods = forecast.properties.periods;
+    let observation = observation.properties;
+    let first = periods.first();
+    let temperature = observation
+        .temperature
+        .celsius()
+        .or_else(|| first.map(NwsPeriod::celsius))
+        .unwrap_or_default();
+    let period_temperature = |daytime: bool| {
+        periods
+            .iter()
+            .find(|period| period.is_daytime == daytime)
+            .map_or(temperature, NwsPeriod::celsius)
+    };
+    let text = if observation.text_description.is_empty() {
+        first.map(|period| period.short_forecast.clone())
+    } else {
+        Some(observation.text_description.clone())
+    }
+    .unwrap_or_default();
+    let weather = WeatherMap {
+        description: text_description(&text),
+    };
+    let offset = first
+        .and_then(|period| period.start_time.as_ref())
+        .map_or_else(|| coordinates.offset(), DateTime::offset);
+    let time = observation
+        .timestamp
+        .clone()
+        .or_else(|| first.and_then(|period| period.start_time.clone()))
+        .unwrap_or_else(DateTime::now)
+        .set_offset(offset);
+    let (sunrise, sunset) = SolarDay::new(coordinates, &time)
+        .sunrise_sunset()
+        .unwrap_or_else(|| (time.clone(), time.clone()));
+    let sea_level = observation.sea_level_pressure.hectopascals();
+    Weather {
+        temperature,
+        icon: weather.icon(),
+        description: text,
+        feels_like: observation
+            .heat_index
+            .celsius()
+            .or_else(|| observation.wind_chill.celsius())
+            .unwrap_or(temperature),
+        min_temperature: period_temperature(false),
+        max_temperature: period_temperature(true),
+        pressure: sea_level
+            .or_else(|| observation.barometric_pressure.hectopascals())
+            .unwrap_or_default(),
+        humidity: observation
+            .relative_humidity
+            .value
+            .unwrap_or_default()
+            .round() as i32,
+        sea_level,
+        ground_level: observation.barometric_pressure.hectopascals(),
+        wind: Wind {
+            speed: observation
+                .wind_speed
+                .meters_per_second()
+                .unwrap_or_default(),
+            degrees: observation.wind_direction.value.unwrap_or_default().round() as i32,
+            gust: observation
+                .wind_gust
+                .meters_per_second()
+                .unwrap_or_default(),
+        },
+        clouds: observation
+            .cloud_layers
+            .iter()
+            .map(|layer| cloud_amount(&layer.amount))
+            .max()
+            .unwrap_or_default(),
+        date_time: time,
+        sunrise,
+        sunset,
+        solar_noon: None,
+        day_length: None,
+        uv_index: None,
+        precipitation: observation.precipitation_last_hour.millimeters(),
+    }
+}
+
+fn point_error(error: ClientError, coordinates: &Coordinates) -> WeatherClientError {
+    if error.is_not_found() {
+        WeatherClientError::OutsideCoverage(
+            coordinates.latitude,
+            coordinates.longitude,
+            WeatherProviderKind::Nws,
+        )
+    } else {
+        error.into()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Nws;
+
+impl Nws {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl WeatherProvider for Nws {
+    async fn get(
+        &self,
+        client: &Client,
+        coordinates: &Coordinates,
+    ) -> Result<Weather, WeatherClientError> {
+        let url = format!(
+            "{}/{lat:.4},{lon:.4}",
+            URL_NWS_POINTS,
+            lat = coordinates.latitude,
+            lon = coordinates.longitude
+        );
+        let point: NwsPointResponse = client
+            .get(&url)
+            .await
+            .map_err(|error| point_error(error, coordinates))?;
+        let forecast: NwsForecastResponse = client.get(&point.properties.forecast).await?;
+        let stations: NwsStationsResponse =
+            client.get(&point.properties.observation_stations).await?;
+        let observation = match stations.observation_stations.first() {
+            Some(station) => {
+                client
+                    .get(&format!("{}/observations/latest", station))
+                    .await?
+            }
+            None => NwsObservationResponse::default(),
+        };
+        Ok(into_weather(forecast, observation, coordinates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::{
+        cloud_amount, into_weather, point_error, text_description, NwsForecastResponse,
+        NwsObservationResponse, NwsPointResponse, NwsStationsResponse,
+    };
+    use crate::{
+        client::{ClientBuilder, ClientError},
+        location::model::Coordinates,
+        weather::{client::WeatherClientError, provider::WeatherProviderKind},
+    };
+
+    #[test]
+    fn nws_text_description() {
+        assert_eq!(text_description("Sunny"), "clear sky");
+        assert_eq!(text_description("Mostly Clear"), "few clouds");
+        assert_eq!(text_description("Partly Cloudy"), "scattered clouds");
+        assert_eq!(text_description("Mostly Cloudy"), "broken clouds");
+        assert_eq!(text_description("Overcast"), "overcast clouds");
+        assert_eq!(text_description("Light Rain"), "light rain");
+        assert_eq!(text_description("Fog/Mist"), "fog/mist");
+    }
+
+    #[test]
+    fn nws_cloud_amount() {
+        assert_eq!(cloud_amount("CLR"), 0);
+        assert_eq!(cloud_amount("SKC"), 0);
+        assert_eq!(cloud_amount("FEW"), 25);
+        assert_eq!(cloud_amount("SCT"), 44);
+        assert_eq!(cloud_amount("BKN"), 75);
+        assert_eq!(cloud_amount("OVC"), 100);
+    }
+
+    #[tokio::test]
+    async fn nws_point_not_found() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://{}/points/51.5074,-0.1278",
+            listener.local_addr().unwrap()
+        );
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut [0; 1024]);
+            stream
+                .write_all(
+                    b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                )
+                .unwrap();
+        });
+        let client = ClientBuilder::new().build().unwrap();
+        let error = client.get::<NwsPointResponse>(&url).await.unwrap_err();
+        server.join().unwrap();
+        let coordinates = Coordinates::new(51.5074, -0.1278);
+        assert!(matches!(
+            point_error(error, &coordinates),
+            WeatherClientError::OutsideCoverage(_, _, WeatherProviderKind::Nws)
+        ));
+
+        let error: ClientError = serde_json::from_str::<NwsPointResponse>("{}")
+            .unwrap_err()
+            .into();
+        assert!(matches!(
+            point_error(error, &coordinates),
+            WeatherClientError::Client(ClientError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn nws_point_and_stations_responses() {
+        let json = "{
+            \"properties\": {
+              \"gridId\": \"OKX\",
+              \"gridX\": 33,
+              \"gridY\": 35,
+              \"forecast\": \"https://api.weather.gov/gridpoints/OKX/33,35/forecast\",
+              \"observationStations\": \"https://api.weather.gov/gridpoints/OKX/33,35/stations\"
+            }
+        }";
+        let point = serde_json::from_str::<NwsPointResponse>(json).unwrap();
+        assert_eq!(
+            point.properties.forecast,
+            "https://api.weather.gov/gridpoints/OKX/33,35/forecast"
+        );
+        assert_eq!(
+            point.properties.observation_stations,
+            "https://api.weather.gov/gridpoints/OKX/33,35/stations"
+        );
+
+        let json = "{
+            \"features\": [],
+            \"observationStations\": [
+              \"https://api.weather.gov/stations/KNYC\",
+              \"https://api.weather.gov/stations/KLGA\"
+            ]
+        }";
+        let stations = serde_json::from_str::<NwsStationsResponse>(json).unwrap();
+        assert_eq!(
+            stations.observation_stations[0],
+            "https://api.weather.gov/stations/KNYC"
+        );
+    }
+
+    #[test]
+    fn weather_from_nws_responses() {
+        let forecast = "{
+            \"properties\": {
+              \"periods\": [
+                {
+                  \"number\": 1,
+                  \"name\": \"Tonight\",
+                  \"startTime\": \"2021-09-14T18:00:00-04:00\",
+                  \"isDaytime\": false,
+                  \"temperature\": 68,
+                  \"temperatureUnit\": \"F\",
+                  \"shortForecast\": \"Mostly Clear\"
+                },
+                {
+                  \"number\": 2,
+                  \"name\": \"Wednesday\",
+                  \"isDaytime\": true,
+                  \"temperature\": 86,
+                  \"temperatureUnit\": \"F\",
+                  \"shortForecast\": \"Sunny\"
+                }
+              ]
+            }
+        }";
+        let observation = "{
+            \"properties\": {
+              \"timestamp\": \"2021-09-14T11:51:00+00:00\",
+              \"textDescription\": \"Light Rain\",
+              \"temperature\": { \"unitCode\": \"wmoUnit:degC\", \"value\": 25 },
+              \"windDirection\": { \"unitCode\": \"wmoUnit:degree_(angle)\", \"value\": 120 },
+              \"windSpeed\": { \"unitCode\": \"wmoUnit:km_h-1\", \"value\": 18 },
+              \"windGust\": { \"unitCode\": \"wmoUnit:km_h-1\", \"value\": null },
+              \"barometricPressure\": { \"unitCode\": \"wmoUnit:Pa\", \"value\": 101320 },
+              \"seaLevelPressure\": { \"unitCode\": \"wmoUnit:Pa\", \"value\": 101680 },
+              \"relativeHumidity\": { \"unitCode\": \"wmoUnit:percent\", \"value\": 55.4 },
+              \"windChill\": { \"unitCode\": \"wmoUnit:degC\", \"value\": null },
+              \"heatIndex\": { \"unitCode\": \"wmoUnit:degC\", \"value\": 26 },
+              \"precipitationLastHour\": { \"unitCode\": \"wmoUnit:m\", \"value\": 0.0018 },
+              \"cloudLayers\": [
+                { \"base\": { \"unitCode\": \"wmoUnit:m\", \"value\": 1200 }, \"amount\": \"FEW\" },
+                { \"base\": { \"unitCode\": \"wmoUnit:m\", \"value\": 3000 }, \"amount\": \"BKN\" }
+              ]
+            }
+        }";
+        let coordinates = Coordinates::new(40.7834, -73.9662);
+        let forecast = serde_json::from_str::<NwsForecastResponse>(forecast).unwrap();
+        let observation = serde_json::from_str::<NwsObservationResponse>(observation).unwrap();
+        let weather = into_weather(forecast.clone(), observation, &coordinates);
+        assert_eq!(weather.temperature, 25.0);
+        assert_eq!(weather.icon, "🌧");
+        assert_eq!(weather.description, "Light Rain");
+        assert_eq!(weather.feels_like, 26.0);
+        assert_eq!(weather.min_temperature, 20.0);
+        assert_eq!(weather.max_temperature, 30.0);
+        assert_eq!(weather.pressure, 1017.0);
+        assert_eq!(weather.humidity, 55);
+        assert_eq!(weather.sea_level, Some(1017.0));
+        assert_eq!(weather.ground_level, Some(1013.0));
+        assert_eq!(weather.precipitation, Some(1.8));
+        assert_eq!(weather.wind.speed, 5.0);
+        assert_eq!(weather.wind.degrees, 120);
+        assert_eq!(weather.wind.gust, 0.0);
+        assert_eq!(weather.clouds, 75);
+        assert_eq!(weather.date_time.to_string(), "2021-09-14T07:51:00-04:00");
+        assert_eq!(weather.sunrise.date(), "2021-09-14");
+        assert_eq!(weather.sunrise.time(), "06:35");
+
+        let weather = into_weather(forecast, NwsObservationResponse::default(), &coordinates);
+        assert_eq!(weather.temperature, 20.0);
+        assert_eq!(weather.description, "Mostly Clear");
+        assert_eq!(weather.icon, "⛅");
+        assert_eq!(weather.max_temperature, 30.0);
+        assert_eq!(weather.precipitation, None);
+        assert_eq!(weather.date_time.to_string(), "2021-09-14T18:00:00-04:00");
+        assert_eq!(weather.sunrise.date(), "2021-09-14");
+    }
+}
@@ -0,0 +1,211 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::{
+    client::Client,
+    datetime::DateTime,
+    location::model::Coordinates,
+    weather::{
+        client::WeatherClientError,
+        model::{Weather, Wind},
+        openweathermap::WeatherMap,
+        provider::WeatherProvider,
+    },
+};
+
+pub const URL_OPEN_METEO: &str = "https://api.open-meteo.com/v1/forecast";
+
+static CURRENT_FIELDS: &str = "temperature_2m,relative_humidity_2m,apparent_temperature,\
+weather_code,cloud_cover,pressure_msl,surface_pressure,wind_speed_10m,wind_direction_10m,\
+wind_gusts_10m,uv_index,precipitation";
+
+static DAILY_FIELDS: &str = "temperature_2m_max,temperature_2m_min,sunrise,sunset";
+
+pub(crate) fn weather_code_description(code: i32) -> &'static str {
+    match code {
+        0 => "clear sky",
+        1 => "few clouds",
+        2 => "scattered clouds",
+        3 => "overcast clouds",
+        45 | 48 => "fog",
+        51 | 53 | 55 => "drizzle",
+        56 | 57 => "freezing drizzle",
+        61 | 63 | 65 => "rain",
+        66 | 67 => "freezing rain",
+        71 | 73 | 75 => "snow",
+        77 => "snow grains",
+        80..=82 => "rain showers",
+        85 | 86 => "snow showers",
+        95 => "thunderstorm",
+        96 | 99 => "thunderstorm with hail",
+        _ => "",
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct OpenMeteoCurrent {
+    time: u64,
+    temperature_2m: f32,
+    relative_humidity_2m: i32,
+    apparent_temperature: f32,
+    weather_code: i32,
+    cloud_cover: i32,
+    pressure_msl: f32,
+    surface_pressure: Option<f32>,
+    wind_speed_10m: Option<f32>,
+    wind_direction_10m: Option<i32>,
+    wind_gusts_10m: Option<f32>,
+    uv_index: Option<f32>,
+    precipitation: Option<f32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct OpenMeteoDaily {
+    temperature_2m_max: Vec<f32>,
+    temperature_2m_min: Vec<f32>,
+    sunrise: Vec<u64>,
+    sunset: Vec<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct OpenMeteoResponse {
+    current: OpenMeteoCurrent,
+    daily: Option<OpenMeteoDaily>,
+    utc_offset_seconds: Option<i32>,
+}
+
+impl From<OpenMeteoResponse> for Weather {
+    fn from(response: OpenMeteoResponse) -> Self {
+        let current = response.current;
+        let daily = response.daily.unwrap_or_default();
+        let offset = response.utc_offset_seconds.unwrap_or_default();
+        let date_time =
+            |value: Option<u64>| DateTime::from_unix(value.unwrap_or_default()).set_offset(offset);
+        let first = |values: &[f32], default: f32| values.first().copied().unwrap_or(default);
+        let weather = WeatherMap {
+            description: weather_code_description(current.weather_code).to_string(),
+        };
+        Self {
+            temperature: current.temperature_2m,
+            icon: weather.icon(),
+            description: weather.format_description(),
+            feels_like: current.apparent_temperature,
+            min_temperature: first(&daily.temperature_2m_min, current.temperature_2m),
+            max_temperature: first(&daily.temperature_2m_max, current.temperature_2m),
+            pressure: current.pressure_msl.round(),
+            humidity: current.relative_humidity_2m,
+            sea_level: Some(current.pressure_msl.round()),
+            ground_level: current.surface_pressure.map(f32::round),
+            wind: Wind {
+                speed: current.wind_speed_10m.unwrap_or_default(),
+                degrees: current.wind_direction_10m.unwrap_or_default(),
+                gust: current.wind_gusts_10m.unwrap_or_default(),
+            },
+            clouds: current.cloud_cover,
+            date_time: date_time(Some(current.time)),
+            sunrise: date_time(daily.sunrise.first().copied()),
+            sunset: date_time(daily.sunset.first().copied()),
+            solar_noon: None,
+            day_length: None,
+            uv_index: current.uv_index,
+            precipitation: current.precipitation,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct OpenMeteo;
+
+impl OpenMeteo {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl WeatherProvider for OpenMeteo {
+    async fn get(
+        &self,
+        client: &Client,
+        coordinates: &Coordinates,
+    ) -> Result<Weather, WeatherClientError> {
+        let url = format!(
+            "{}?latitude={lat}&longitude={lon}&current={current}&daily={daily}\
+&wind_speed_unit=ms&timeformat=unixtime&timezone=auto&forecast_days=1",
+            URL_OPEN_METEO,
+            lat = coordinates.latitude,
+            lon = coordinates.longitude,
+            current = CURRENT_FIELDS,
+            daily = DAILY_FIELDS
+        );
+        let res: OpenMeteoResponse = client.get(&url).await?;
+        Ok(res.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{weather_code_description, OpenMeteoResponse, Weather};
+
+    #[test]
+    fn open_meteo_weather_code_description() {
+        assert_eq!(weather_code_description(0), "clear sky");
+        assert_eq!(weather_code_description(3), "overcast clouds");
+        assert_eq!(weather_code_description(63), "rain");
+        assert_eq!(weather_code_description(81), "rain showers");
+        assert_eq!(weather_code_description(99), "thunderstorm with hail");
+        assert_eq!(weather_code_description(42), "");
+    }
+
+    #[test]
+    fn weather_from_open_meteo_response() {
+        let json = "{
+            \"utc_offset_seconds\": -10800,
+            \"current\": {
+              \"time\": 1631620646,
+              \"interval\": 900,
+              \"temperature_2m\": 25.8,
+              \"relative_humidity_2m\": 55,
+              \"apparent_temperature\": 25.87,
+              \"weather_code\": 2,
+              \"cloud_cover\": 46,
+              \"pressure_msl\": 1016.8,
+              \"surface_pressure\": 949.2,
+              \"wind_speed_10m\": 4.72,
+              \"wind_direction_10m\": 115,
+              \"wind_gusts_10m\": 6.14,
+              \"uv_index\": 7.35,
+              \"precipitation\": 0.4
+            },
+            \"daily\": {
+              \"time\": [1631577600],
+              \"temperature_2m_max\": [28.7],
+              \"temperature_2m_min\": [24.1],
+              \"sunrise\": [1631607769],
+              \"sunset\": [1631651152]
+            }
+        }";
+        let response = serde_json::from_str::<OpenMeteoResponse>(json);
+        assert!(response.is_ok());
+        let weather: Weather = response.unwrap().into();
+        assert_eq!(weather.temperature, 25.8);
+        assert_eq!(weather.icon, "☁️");
+        assert_eq!(weather.description, "Scattered clouds");
+        assert_eq!(weather.feels_like, 25.87);
+        assert_eq!(weather.min_temperature, 24.1);
+        assert_eq!(weather.max_temperature, 28.7);
+        assert_eq!(weather.pressure, 1017.0);
+        assert_eq!(weather.humidity, 55);
+        assert_eq!(weather.sea_level, Some(1017.0));
+        assert_eq!(weather.ground_level, Some(949.0));
+        assert_eq!(weather.precipitation, Some(0.4));
+        assert_eq!(weather.wind.speed, 4.72);
+        assert_eq!(weather.wind.degrees, 115);
+        assert_eq!(weather.wind.gust, 6.14);
+        assert_eq!(weather.clouds, 46);
+        assert_eq!(weather.date_time.to_string(), "2021-09-14T08:57:26-03:00");
+        assert_eq!(weather.sunrise.to_string(), "2021-09-14T05:22:49-03:00");
+        assert_eq!(weather.sunset.to_string(), "2021-09-14T17:25:52-03:00");
+        assert_eq!(weather.uv_index, Some(7.35));
+    }
+}
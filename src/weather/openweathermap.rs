@@ -0,0 +1,218 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::{
+    client::{Client, ClientError},
+    datetime::DateTime,
+    emoji::get_emoji,
+    location::model::Coordinates,
+    weather::{
+        client::WeatherClientError,
+        model::{Weather, Wind},
+        provider::WeatherProvider,
+    },
+};
+
+pub const URL_WEATHER: &str = "http://api.openweathermap.org/data/2.5/weather";
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(crate) struct WeatherMap {
+    pub(crate) description: String,
+}
+
+impl WeatherMap {
+    pub(crate) fn icon(&self) -> String {
+        get_emoji(&self.description).unwrap_or_default().to_string()
+    }
+
+    pub(crate) fn format_description(&self) -> String {
+        let mut chars = self.description.chars();
+        match chars.next() {
+            Some(first) => format!("{}{}", first.to_uppercase(), chars.as_str()),
+            None => String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(crate) struct WeatherMain {
+    pub(crate) temp: f32,
+    pub(crate) feels_like: f32,
+    pub(crate) temp_min: f32,
+    pub(crate) temp_max: f32,
+    pub(crate) pressure: f32,
+    pub(crate) humidity: i32,
+    pub(crate) sea_level: Option<f32>,
+    pub(crate) grnd_level: Option<f32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(crate) struct WeatherWindMap {
+    speed: Option<f32>,
+    deg: Option<i32>,
+    gust: Option<f32>,
+}
+
+impl From<WeatherWindMap> for Wind {
+    fn from(response: WeatherWindMap) -> Self {
+        Wind {
+            speed: response.speed.unwrap_or_default(),
+            degrees: response.deg.unwrap_or_default(),
+            gust: response.gust.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(crate) struct WeatherClouds {
+    pub(crate) all: i32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct WeatherPrecipitation {
+    #[serde(rename = "1h")]
+    one_hour: Option<f32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct WeatherSys {
+    sunrise: u64,
+    sunset: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct WeatherResponse {
+    weather: Option<Vec<WeatherMap>>,
+    main: Option<WeatherMain>,
+    wind: Option<WeatherWindMap>,
+    clouds: Option<WeatherClouds>,
+    rain: Option<WeatherPrecipitation>,
+    snow: Option<WeatherPrecipitation>,
+    dt: Option<u64>,
+    sys: Option<WeatherSys>,
+    timezone: Option<i32>,
+}
+
+impl From<WeatherResponse> for Weather {
+    fn from(response: WeatherResponse) -> Self {
+        let weather = response
+            .weather
+            .unwrap_or_default()
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        let main = response.main.unwrap_or_default();
+        let sys = response.sys.unwrap_or_default();
+        let offset = response.timezone.unwrap_or_default();
+        let precipitation = [&response.rain, &response.snow]
+            .iter()
+            .filter_map(|precipitation| precipitation.as_ref()?.one_hour)
+            .reduce(|rain, snow| rain + snow);
+        Self {
+            temperature: main.temp,
+            icon: weather.icon(),
+            description: weather.format_description(),
+            feels_like: main.feels_like,
+            min_temperature: main.temp_min,
+            max_temperature: main.temp_max,
+            pressure: main.pressure,
+            humidity: main.humidity,
+            sea_level: main.sea_level,
+            ground_level: main.grnd_level,
+            wind: response.wind.unwrap_or_default().into(),
+            clouds: response.clouds.unwrap_or_default().all,
+            date_time: DateTime::from_unix(response.dt.unwrap_or_default()).set_offset(offset),
+            sunrise: DateTime::from_unix(sys.sunrise).set_offset(offset),
+            sunset: DateTime::from_unix(sys.sunset).set_offset(offset),
+            solar_noon: None,
+            day_length: None,
+            uv_index: None,
+            precipitation,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OpenWeatherMap {
+    api_key: String,
+}
+
+impl OpenWeatherMap {
+    pub fn new(api_key: &str) -> Self {
+        Self {
+            api_key: api_key.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl WeatherProvider for OpenWeatherMap {
+    async fn get(
+        &self,
+        client: &Client,
+        coordinates: &Coordinates,
+    ) -> Result<Weather, WeatherClientError> {
+        let url = format!(
+            "{}?lat={lat}&lon={lon}&units=metric&appid={appid}",
+            URL_WEATHER,
+            lat = coordinates.latitude,
+            lon = coordinates.longitude,
+            appid = self.api_key
+        );
+        let res: WeatherResponse = client.get(&url).await.map_err(ClientError::for_api_key)?;
+        Ok(res.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Weather, WeatherResponse};
+
+    #[test]
+    fn weather_from_response() {
+        let json = "{
+            \"weather\": [
+              {
+                \"description\": \"scattered clouds\"
+              }
+            ],
+            \"main\": {
+              \"temp\": 25.8,
+              \"feels_like\": 25.87,
+              \"temp_min\": 25.8,
+              \"temp_max\": 25.8,
+              \"pressure\": 1017,
+              \"humidity\": 55,
+              \"sea_level\": 1017,
+              \"grnd_level\": 949
+            },
+            \"wind\": { \"speed\": 4.72, \"deg\": 115, \"gust\": 6.14 },
+            \"clouds\": { \"all\": 46 },
+            \"rain\": { \"1h\": 0.35 },
+            \"dt\": 1631620646,
+            \"sys\": { \"country\": \"BR\", \"sunrise\": 1631607769, \"sunset\": 1631651152 },
+            \"timezone\": -10800
+        }";
+        let response = serde_json::from_str::<WeatherResponse>(json);
+        assert!(response.is_ok());
+        let weather: Weather = response.unwrap().into();
+        assert_eq!(weather.temperature, 25.8);
+        assert_eq!(weather.icon, "☁️");
+        assert_eq!(weather.description, "Scattered clouds");
+        assert_eq!(weather.feels_like, 25.87);
+        assert_eq!(weather.min_temperature, 25.8);
+        assert_eq!(weather.max_temperature, 25.8);
+        assert_eq!(weather.pressure, 1017.0);
+        assert_eq!(weather.humidity, 55);
+        assert_eq!(weather.sea_level, Some(1017.0));
+        assert_eq!(weather.ground_level, Some(949.0));
+        assert_eq!(weather.precipitation, Some(0.35));
+        assert_eq!(weather.wind.speed, 4.72);
+        assert_eq!(weather.wind.degrees, 115);
+        assert_eq!(weather.wind.gust, 6.14);
+        assert_eq!(weather.clouds, 46);
+        assert_eq!(weather.date_time.to_string(), "2021-09-14T08:57:26-03:00");
+        assert_eq!(weather.sunrise.to_string(), "2021-09-14T05:22:49-03:00");
+        assert_eq!(weather.sunset.to_string(), "2021-09-14T17:25:52-03:00");
+    }
+}
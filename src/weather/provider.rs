@@ -0,0 +1,136 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    result,
+    str::FromStr,
+};
+
+use async_trait::async_trait;
+use serde::{de, Deserialize, Deserializer};
+use thiserror::Error;
+
+use crate::{
+    client::Client,
+    location::model::Coordinates,
+    weather::{
+        client::WeatherClientError, met_norway::MetNorway, model::Weather, nws::Nws,
+        open_meteo::OpenMeteo, openweathermap::OpenWeatherMap,
+    },
+};
+
+#[async_trait]
+pub trait WeatherProvider: Send + Sync {
+    async fn get(
+        &self,
+        client: &Client,
+        coordinates: &Coordinates,
+    ) -> result::Result<Weather, WeatherClientError>;
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum WeatherProviderKind {
+    #[default]
+    OpenWeatherMap,
+    OpenMeteo,
+    MetNorway,
+    Nws,
+}
+
+impl WeatherProviderKind {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Self::OpenWeatherMap => "openweathermap",
+            Self::OpenMeteo => "open-meteo",
+            Self::MetNorway => "met-norway",
+            Self::Nws => "nws",
+        }
+    }
+
+    // MET Norway's terms require honoring Expires and If-Modified-Since.
+    pub fn requires_cache(&self) -> bool {
+        matches!(self, Self::MetNorway)
+    }
+
+    pub fn provider(&self, api_key: &str) -> Box<dyn WeatherProvider> {
+        match *self {
+            Self::OpenWeatherMap => Box::new(OpenWeatherMap::new(api_key)),
+            Self::OpenMeteo => Box::new(OpenMeteo::new()),
+            Self::MetNorway => Box::new(MetNorway::new()),
+            Self::Nws => Box::new(Nws::new()),
+        }
+    }
+}
+
+impl Display for WeatherProviderKind {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+        write!(fmt, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid weather provider `{0}`, expected openweathermap, open-meteo, met-norway or nws")]
+pub struct WeatherProviderParseError(String);
+
+impl FromStr for WeatherProviderKind {
+    type Err = WeatherProviderParseError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "openweathermap" | "owm" => Ok(Self::OpenWeatherMap),
+            "open-meteo" | "openmeteo" => Ok(Self::OpenMeteo),
+            "met-norway" | "met" | "yr" => Ok(Self::MetNorway),
+            "nws" | "weather.gov" => Ok(Self::Nws),
+            _ => Err(WeatherProviderParseError(value.to_string())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WeatherProviderKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WeatherProviderKind, WeatherProviderParseError};
+
+    #[test]
+    fn weather_provider_kind_to_string() {
+        assert_eq!(
+            WeatherProviderKind::OpenWeatherMap.to_string(),
+            "openweathermap"
+        );
+        assert_eq!(WeatherProviderKind::OpenMeteo.to_string(), "open-meteo");
+        assert_eq!(WeatherProviderKind::MetNorway.to_string(), "met-norway");
+        assert_eq!(WeatherProviderKind::Nws.to_string(), "nws");
+    }
+
+    #[test]
+    fn weather_provider_kind_requires_cache() {
+        assert!(WeatherProviderKind::MetNorway.requires_cache());
+        assert!(!WeatherProviderKind::OpenWeatherMap.requires_cache());
+        assert!(!WeatherProviderKind::OpenMeteo.requires_cache());
+        assert!(!WeatherProviderKind::Nws.requires_cache());
+    }
+
+    #[test]
+    fn weather_provider_kind_from_str() {
+        assert_eq!(
+            "openweathermap".parse(),
+            Ok(WeatherProviderKind::OpenWeatherMap)
+        );
+        assert_eq!("OWM".parse(), Ok(WeatherProviderKind::OpenWeatherMap));
+        assert_eq!("open-meteo".parse(), Ok(WeatherProviderKind::OpenMeteo));
+        assert_eq!("OpenMeteo".parse(), Ok(WeatherProviderKind::OpenMeteo));
+        assert_eq!("met-norway".parse(), Ok(WeatherProviderKind::MetNorway));
+        assert_eq!("yr".parse(), Ok(WeatherProviderKind::MetNorway));
+        assert_eq!("NWS".parse(), Ok(WeatherProviderKind::Nws));
+        assert_eq!("weather.gov".parse(), Ok(WeatherProviderKind::Nws));
+        assert_eq!(
+            "dwd".parse::<WeatherProviderKind>(),
+            Err(WeatherProviderParseError("dwd".to_string()))
+        );
+    }
+}